@@ -18,9 +18,12 @@
  * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
  */
 
+use crate::config::config;
+use crate::log::log_debug;
 pub use ahash::AHashMap;
 use core::hash::Hash;
-pub use once_cell::unsync::Lazy;
+pub use once_cell::sync::Lazy;
+use std::collections::BTreeMap;
 use std::sync::Arc;
 pub use std::sync::Mutex;
 /**************************** Constants**************************************/
@@ -34,30 +37,219 @@ pub const DEFAULT_CACHE_PAGE_SIZE: usize = 10;
 /// the search parsing object here.
 ///
 pub type RUMCache<K, V> = AHashMap<K, V>;
-pub type LazyRUMCache<K, V> = Lazy<Arc<RUMCache<K, V>>>;
+///
+/// Shared, lazily initialized cache. The inner [RUMCache] sits behind a [Mutex] so every clone of
+/// the [Arc] can insert into it.
+///
+pub type LazyRUMCache<K, V> = Lazy<Arc<Mutex<RUMCache<K, V>>>>;
+pub type SharedBoundedRUMCache<K, V> = Arc<Mutex<BoundedRUMCache<K, V>>>;
+pub type LazyBoundedRUMCache<K, V> = Lazy<SharedBoundedRUMCache<K, V>>;
+
+///
+/// Cache holding at most `limit` entries. Once full, inserting a new key evicts the least
+/// recently used one. Both [get](BoundedRUMCache::get) and [insert](BoundedRUMCache::insert)
+/// count as a use.
+///
+/// ```
+/// use rumtk_core::cache::BoundedRUMCache;
+///
+/// let mut cache: BoundedRUMCache<&str, u32> = BoundedRUMCache::new(2);
+/// cache.insert("MSH", 1);
+/// cache.insert("PID", 2);
+/// cache.get(&"MSH");
+/// let evicted = cache.insert("OBX", 3);
+///
+/// assert_eq!(evicted, Some(("PID", 2)), "The least recently used key was not evicted!");
+/// assert_eq!(cache.len(), cache.limit(), "Cache grew past its limit!");
+/// ```
+///
+#[derive(Debug, Clone)]
+pub struct BoundedRUMCache<K, V> {
+    entries: AHashMap<K, (V, u64)>,
+    recency: BTreeMap<u64, K>,
+    tick: u64,
+    limit: usize,
+}
+
+impl<K, V> BoundedRUMCache<K, V>
+where
+    K: Hash + Eq + Clone,
+{
+    ///
+    /// Create a cache holding at most `limit` entries. A limit of 0 is treated as 1.
+    ///
+    pub fn new(limit: usize) -> Self {
+        let limit = limit.max(1);
+        BoundedRUMCache {
+            entries: AHashMap::with_capacity(limit.min(config().cache_capacity)),
+            recency: BTreeMap::new(),
+            tick: 0,
+            limit,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.entries.contains_key(key)
+    }
+
+    ///
+    /// Look up `key` and mark it as the most recently used entry.
+    ///
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        let tick = self.next_tick();
+        let (_, last_used) = self.entries.get_mut(key)?;
+        let owner = self.recency.remove(last_used)?;
+        *last_used = tick;
+        self.recency.insert(tick, owner);
+        self.entries.get(key).map(|(value, _)| value)
+    }
+
+    ///
+    /// Insert or replace `key`. If that pushes the cache past its limit, the least recently used
+    /// entry is removed and returned.
+    ///
+    pub fn insert(&mut self, key: K, value: V) -> Option<(K, V)> {
+        let tick = self.next_tick();
+        if let Some((_, last_used)) = self.entries.insert(key.clone(), (value, tick)) {
+            self.recency.remove(&last_used);
+        }
+        self.recency.insert(tick, key);
+        if self.entries.len() <= self.limit {
+            return None;
+        }
+        let (_, oldest) = self.recency.pop_first()?;
+        let (value, _) = self.entries.remove(&oldest)?;
+        Some((oldest, value))
+    }
+
+    fn next_tick(&mut self) -> u64 {
+        self.tick += 1;
+        self.tick
+    }
+}
 
 /**************************** Traits ****************************************/
 
 /**************************** Helpers ***************************************/
+///
+/// Create a lazily initialized cache. Its initial capacity comes from
+/// [RumtkConfig::cache_capacity](crate::config::RumtkConfig::cache_capacity) at first access.
+///
 pub const fn new_cache<K, V>() -> LazyRUMCache<K, V> {
-    LazyRUMCache::new(|| Arc::new(RUMCache::with_capacity(DEFAULT_CACHE_PAGE_SIZE)))
+    LazyRUMCache::new(|| Arc::new(Mutex::new(RUMCache::with_capacity(config().cache_capacity))))
 }
 
-pub fn get_or_set_from_cache<'a, K, V, F>(
-    cache: &'a mut LazyRUMCache<K, V>,
+///
+/// Look up `expr` in the cache and pass a reference to the entry to `f`. On a miss, `new_fn`
+/// builds the value and it gets stored first. The lock is held while `new_fn` and `f` run, so
+/// neither may touch the same cache.
+///
+/// Use this instead of [get_or_set_from_cache] when the value is expensive to clone.
+///
+/// ```
+/// use rumtk_core::cache::{new_cache, with_cached, LazyRUMCache};
+///
+/// static CACHE: LazyRUMCache<u32, Vec<u32>> = new_cache();
+///
+/// let len = with_cached(&CACHE, &4, |n| vec![0; *n as usize], |v| v.len());
+///
+/// assert_eq!(len, 4, "The closure did not see the cached entry!");
+/// ```
+///
+pub fn with_cached<K, V, F, G, R>(
+    cache: &Arc<Mutex<RUMCache<K, V>>>,
     expr: &K,
     new_fn: F,
-) -> &'a V
+    f: G,
+) -> R
+where
+    K: Hash + Eq + Clone,
+    F: Fn(&K) -> V,
+    G: FnOnce(&V) -> R,
+{
+    let mut cache_ref = cache.lock().unwrap_or_else(|e| e.into_inner());
+    if !cache_ref.contains_key(expr) {
+        log_debug(format_args!("Cache miss! Creating new cache entry."));
+        let value = new_fn(expr);
+        cache_ref.insert(expr.clone(), value);
+    }
+    f(&cache_ref[expr])
+}
+
+///
+/// Look up `expr` in the cache. On a miss, `new_fn` builds the value and it gets stored. The
+/// lock is held while `new_fn` runs, so `new_fn` must not touch the same cache.
+///
+/// **Breaking change:** this used to return `&V`. The entry cannot outlive the lock, so the value
+/// is now returned as a clone. Store cheap to clone values (e.g. [Arc] or [Regex](regex::Regex))
+/// when the payload is large, or use [with_cached] to borrow the entry instead.
+///
+pub fn get_or_set_from_cache<K, V, F>(cache: &Arc<Mutex<RUMCache<K, V>>>, expr: &K, new_fn: F) -> V
+where
+    K: Hash + Eq + Clone,
+    V: Clone,
+    F: Fn(&K) -> V,
+{
+    with_cached(cache, expr, new_fn, V::clone)
+}
+
+///
+/// Create a shareable [BoundedRUMCache]. Wrap it in [Lazy] to use it as a global.
+///
+/// ```
+/// use rumtk_core::cache::{new_bounded_cache, Lazy, LazyBoundedRUMCache};
+///
+/// static CACHE: LazyBoundedRUMCache<u32, u32> = Lazy::new(|| new_bounded_cache(64));
+///
+/// assert_eq!(CACHE.lock().unwrap().limit(), 64, "Wrong limit!");
+/// ```
+///
+pub fn new_bounded_cache<K, V>(limit: usize) -> SharedBoundedRUMCache<K, V>
+where
+    K: Hash + Eq + Clone,
+{
+    Arc::new(Mutex::new(BoundedRUMCache::new(limit)))
+}
+
+///
+/// Bounded counterpart of [get_or_set_from_cache]. A hit marks the entry as recently used. A miss
+/// may evict the least recently used entry.
+///
+pub fn get_or_set_from_bounded_cache<K, V, F>(
+    cache: &SharedBoundedRUMCache<K, V>,
+    expr: &K,
+    new_fn: F,
+) -> V
 where
     K: Hash + Eq + Clone,
     V: Clone,
     F: Fn(&K) -> V,
 {
-    if !cache.contains_key(expr) {
-        let mut cache_ref = Arc::get_mut(cache).unwrap();
-        cache_ref.insert(expr.clone(), new_fn(expr).clone());
+    let mut cache_ref = cache.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(value) = cache_ref.get(expr) {
+        return value.clone();
+    }
+    log_debug(format_args!("Cache miss! Creating new cache entry."));
+    let value = new_fn(expr);
+    if cache_ref.insert(expr.clone(), value.clone()).is_some() {
+        log_debug(format_args!(
+            "Cache limit of {} reached! Evicted least recently used entry.",
+            cache_ref.limit()
+        ));
     }
-    cache.get(expr).unwrap()
+    value
 }
 
 pub mod cache_macros {
@@ -76,11 +268,11 @@ pub mod cache_macros {
     ///    String::from(k)
     /// }
     ///
-    /// let mut cache: StringCache = new_cache();
+    /// let cache: StringCache = new_cache();
     ///
     /// let test_key: String = String::from("Hello World");
     /// let v = rumtk_cache_fetch!(
-    ///     &mut cache,
+    ///     &cache,
     ///     &test_key,
     ///     init_cache
     /// );
@@ -94,7 +286,32 @@ pub mod cache_macros {
     macro_rules! rumtk_cache_fetch {
         ( $cache:expr, $key:expr, $func:expr ) => {{
             use $crate::cache::get_or_set_from_cache;
-            unsafe { get_or_set_from_cache($cache, $key, $func) }
+            get_or_set_from_cache($cache, $key, $func)
+        }};
+    }
+
+    ///
+    /// Same as [rumtk_cache_fetch] but for a [BoundedRUMCache](crate::cache::BoundedRUMCache).
+    ///
+    /// ```
+    /// use rumtk_core::rumtk_cache_fetch_bounded;
+    /// use rumtk_core::cache::new_bounded_cache;
+    ///
+    /// let cache = new_bounded_cache::<u32, u32>(2);
+    /// for key in [1, 2, 3] {
+    ///     rumtk_cache_fetch_bounded!(&cache, &key, |k| k * 10);
+    /// }
+    /// let v = rumtk_cache_fetch_bounded!(&cache, &3, |k| k * 10);
+    ///
+    /// assert_eq!(v, 30, "Wrong cached value!");
+    /// assert_eq!(cache.lock().unwrap().len(), 2, "Cache grew past its limit!");
+    /// ```
+    ///
+    #[macro_export]
+    macro_rules! rumtk_cache_fetch_bounded {
+        ( $cache:expr, $key:expr, $func:expr ) => {{
+            use $crate::cache::get_or_set_from_bounded_cache;
+            get_or_set_from_bounded_cache($cache, $key, $func)
         }};
     }
 }
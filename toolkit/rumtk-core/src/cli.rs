@@ -19,7 +19,7 @@
  */
 
 pub mod cli_utils {
-    use crate::core::RUMResult;
+    use crate::core::{RUMError, RUMResult};
     use crate::strings::{format_compact, RUMArrayConversions, RUMString};
     use clap::Parser;
     use compact_str::CompactStringExt;
@@ -115,7 +115,10 @@ pub mod cli_utils {
                 buf.extend_from_slice(&chunk);
                 Ok(s)
             }
-            Err(e) => Err(format_compact!("Error reading stdin chunk because {}!", e)),
+            Err(e) => Err(RUMError::io(
+                e.kind(),
+                format_compact!("Error reading stdin chunk because {}!", e),
+            )),
         }
     }
 
@@ -124,9 +127,15 @@ pub mod cli_utils {
         match stdout_handle.write_all(data.as_bytes()) {
             Ok(_) => match stdout_handle.flush() {
                 Ok(_) => Ok(()),
-                Err(e) => Err(format_compact!("Error flushing stdout: {}", e)),
+                Err(e) => Err(RUMError::io(
+                    e.kind(),
+                    format_compact!("Error flushing stdout: {}", e),
+                )),
             },
-            Err(e) => Err(format_compact!("Error writing to stdout!")),
+            Err(e) => Err(RUMError::io(
+                e.kind(),
+                format_compact!("Error writing to stdout!"),
+            )),
         }
     }
 
@@ -0,0 +1,105 @@
+/*
+ * rumtk attempts to implement HL7 and medical protocols for interoperability in medicine.
+ * This toolkit aims to be reliable, simple, performant, and standards compliant.
+ * Copyright (C) 2025  Luis M. Santos, M.D.
+ *
+ * This library is free software; you can redistribute it and/or
+ * modify it under the terms of the GNU Lesser General Public
+ * License as published by the Free Software Foundation; either
+ * version 2.1 of the License, or (at your option) any later version.
+ *
+ * This library is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+ * Lesser General Public License for more details.
+ *
+ * You should have received a copy of the GNU Lesser General Public
+ * License along with this library; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
+ */
+use crate::cache::DEFAULT_CACHE_PAGE_SIZE;
+use crate::strings::{RUMString, RedactionPolicy};
+use crate::threading::threading_functions::get_default_system_thread_count;
+use once_cell::sync::Lazy;
+use std::sync::RwLock;
+
+/**************************** Types *****************************************/
+
+///
+/// Process wide settings for the toolkit. Read the active configuration with [config] and change
+/// it with [set_config] or [update_config].
+///
+/// Components read the configuration when they initialize, not continuously. Changing a value
+/// affects components initialized afterwards; it never alters one that already exists. For
+/// example, a [LazyRUMCache](crate::cache::LazyRUMCache) allocates `cache_capacity` entries on
+/// first access and keeps that allocation. Configure the toolkit at startup, before using it, to
+/// get consistent behavior everywhere.
+///
+/// There is no interpreter isolation setting. pyo3 does not support CPython sub-interpreters, so
+/// every [RUMPyInterpreter](crate::scripting::python_utils::RUMPyInterpreter) shares the one
+/// embedded interpreter, including `sys.path` and `sys.modules`.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RumtkConfig {
+    /// Number of entries pre-allocated by caches created with
+    /// [new_cache](crate::cache::new_cache). Read on the cache's first access.
+    pub cache_capacity: usize,
+    /// Directories appended to Python's `sys.path` before a module is loaded with
+    /// [py_load](crate::scripting::python_utils::py_load), so it can import its siblings. Read on
+    /// every load.
+    pub python_search_paths: Vec<RUMString>,
+    /// Number of workers in the global [thread_pool](crate::threading::thread_pool). Read once,
+    /// when the pool is first used. Defaults to the number of CPU threads.
+    pub thread_pool_size: usize,
+    /// Policy used by [redact_default](crate::strings::redact_default). Read on every call.
+    /// Defaults to [RedactionPolicy::Full].
+    pub redaction_policy: RedactionPolicy,
+}
+
+impl Default for RumtkConfig {
+    fn default() -> Self {
+        RumtkConfig {
+            cache_capacity: DEFAULT_CACHE_PAGE_SIZE,
+            python_search_paths: Vec::new(),
+            thread_pool_size: get_default_system_thread_count(),
+            redaction_policy: RedactionPolicy::default(),
+        }
+    }
+}
+
+/**************************** Globals ***************************************/
+static CONFIG: Lazy<RwLock<RumtkConfig>> = Lazy::new(|| RwLock::new(RumtkConfig::default()));
+
+/**************************** Helpers ***************************************/
+
+///
+/// Snapshot of the active configuration.
+///
+pub fn config() -> RumtkConfig {
+    CONFIG.read().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
+///
+/// Replace the active configuration. See [RumtkConfig] for when components pick up changes.
+///
+pub fn set_config(new_config: RumtkConfig) {
+    *CONFIG.write().unwrap_or_else(|e| e.into_inner()) = new_config;
+}
+
+///
+/// Change part of the active configuration in place. Unlike a [config] then [set_config] round
+/// trip, concurrent updates to different fields cannot overwrite each other.
+///
+/// # Example
+/// ```
+///  use rumtk_core::config::{config, update_config};
+///  update_config(|c| c.cache_capacity = 128);
+///  assert_eq!(128, config().cache_capacity);
+///```
+///
+pub fn update_config<F>(f: F)
+where
+    F: FnOnce(&mut RumtkConfig),
+{
+    f(&mut CONFIG.write().unwrap_or_else(|e| e.into_inner()));
+}
@@ -17,17 +17,524 @@
  * License along with this library; if not, write to the Free Software
  * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
  */
+use crate::log::log_warn;
 use crate::strings::RUMString;
 use compact_str::format_compact;
 pub use smallvec::{smallvec, SmallVec};
+#[cfg(feature = "backtrace")]
+use std::backtrace::{Backtrace, BacktraceStatus};
+use std::fmt::Display;
+use std::io::ErrorKind;
+use std::panic::{catch_unwind, UnwindSafe};
+#[cfg(feature = "backtrace")]
+use std::sync::Arc;
+
+pub const ERROR_CODE_IO: u32 = 1000;
+pub const ERROR_CODE_PARSE: u32 = 2000;
+pub const ERROR_CODE_PYTHON: u32 = 3000;
+pub const ERROR_CODE_CACHE: u32 = 4000;
+pub const ERROR_CODE_OTHER: u32 = 9000;
+
+///
+/// Stack trace attached to every [RUMError] when it is built.
+///
+/// With the `backtrace` feature enabled, capture follows [Backtrace::capture]. A trace is recorded
+/// only when `RUST_LIB_BACKTRACE` or `RUST_BACKTRACE` enables it. The standard library reads
+/// those variables once and caches the answer. Without the feature this type has no fields, so
+/// carrying it costs nothing. Traces never take part in error equality.
+///
+#[derive(Debug, Clone, Default)]
+pub struct RUMBacktrace {
+    #[cfg(feature = "backtrace")]
+    trace: Option<Arc<Backtrace>>,
+}
+
+impl RUMBacktrace {
+    #[cfg(feature = "backtrace")]
+    pub fn capture() -> RUMBacktrace {
+        let trace = Backtrace::capture();
+        RUMBacktrace {
+            trace: (trace.status() == BacktraceStatus::Captured).then(|| Arc::new(trace)),
+        }
+    }
+
+    #[cfg(not(feature = "backtrace"))]
+    pub fn capture() -> RUMBacktrace {
+        RUMBacktrace {}
+    }
+}
+
+impl PartialEq for RUMBacktrace {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for RUMBacktrace {}
+
+///
+/// Error type used throughout the toolkit. The variant tells callers which subsystem failed so
+/// they can match on it, and the message keeps the human readable details.
+///
+/// Build errors with the constructors ([RUMError::io], [RUMError::parse], ...) so the
+/// [RUMBacktrace] of every variant is captured where the error happened.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RUMError {
+    /// File, socket, or other I/O failure. Keeps the [ErrorKind] so callers can tell, for example,
+    /// a missing file apart from a permission problem.
+    Io(ErrorKind, RUMString, RUMBacktrace),
+    /// Input could not be parsed, decoded, or validated.
+    Parse(RUMString, RUMBacktrace),
+    /// Failure raised by or while talking to the Python interpreter.
+    Python(RUMString, RUMBacktrace),
+    /// Failure while reading or populating a cache.
+    Cache(RUMString, RUMBacktrace),
+    /// Anything that does not fit the other categories.
+    Other(RUMString, RUMBacktrace),
+    /// An error from a lower layer annotated with what we were doing when it happened.
+    /// See [RUMResultExt::with_context].
+    Context {
+        context: RUMString,
+        source: Box<RUMError>,
+        backtrace: RUMBacktrace,
+    },
+}
+
+impl RUMError {
+    pub fn io<M: Into<RUMString>>(kind: ErrorKind, message: M) -> RUMError {
+        RUMError::Io(kind, message.into(), RUMBacktrace::capture())
+    }
+
+    pub fn parse<M: Into<RUMString>>(message: M) -> RUMError {
+        RUMError::Parse(message.into(), RUMBacktrace::capture())
+    }
+
+    pub fn python<M: Into<RUMString>>(message: M) -> RUMError {
+        RUMError::Python(message.into(), RUMBacktrace::capture())
+    }
+
+    pub fn cache<M: Into<RUMString>>(message: M) -> RUMError {
+        RUMError::Cache(message.into(), RUMBacktrace::capture())
+    }
+
+    pub fn other<M: Into<RUMString>>(message: M) -> RUMError {
+        RUMError::Other(message.into(), RUMBacktrace::capture())
+    }
+
+    pub fn message(&self) -> &RUMString {
+        match self {
+            RUMError::Io(_, message, _) => message,
+            RUMError::Parse(message, _) => message,
+            RUMError::Python(message, _) => message,
+            RUMError::Cache(message, _) => message,
+            RUMError::Other(message, _) => message,
+            RUMError::Context { context, .. } => context,
+        }
+    }
+
+    ///
+    /// Stable numeric code identifying the kind of error, for FFI consumers and dashboards that
+    /// should not parse messages. Codes never change once published. Each category owns a range
+    /// of 1000 codes:
+    ///
+    /// | Range     | Category           | Codes                                               |
+    /// |-----------|--------------------|-----------------------------------------------------|
+    /// | 1000-1999 | [RUMError::Io]     | `1000` + the index of the [ErrorKind] (see below)   |
+    /// | 2000-2999 | [RUMError::Parse]  | `2000`                                              |
+    /// | 3000-3999 | [RUMError::Python] | `3000`                                              |
+    /// | 4000-4999 | [RUMError::Cache]  | `4000`                                              |
+    /// | 9000-9999 | [RUMError::Other]  | `9000`                                              |
+    ///
+    /// [RUMError::Io] codes are `1001` for [ErrorKind::NotFound], `1002` for
+    /// [ErrorKind::PermissionDenied], `1003` for [ErrorKind::ConnectionRefused], `1004` for
+    /// [ErrorKind::ConnectionReset], `1005` for [ErrorKind::ConnectionAborted], `1006` for
+    /// [ErrorKind::NotConnected], `1007` for [ErrorKind::AddrInUse], `1008` for
+    /// [ErrorKind::AddrNotAvailable], `1009` for [ErrorKind::BrokenPipe], `1010` for
+    /// [ErrorKind::AlreadyExists], `1011` for [ErrorKind::WouldBlock], `1012` for
+    /// [ErrorKind::InvalidInput], `1013` for [ErrorKind::InvalidData], `1014` for
+    /// [ErrorKind::TimedOut], `1015` for [ErrorKind::WriteZero], `1016` for
+    /// [ErrorKind::Interrupted], `1017` for [ErrorKind::Unsupported], `1018` for
+    /// [ErrorKind::UnexpectedEof], `1019` for [ErrorKind::OutOfMemory], and `1000` for any other
+    /// kind.
+    ///
+    /// [RUMError::Context] reports the code of the error it wraps.
+    ///
+    pub fn code(&self) -> u32 {
+        match self {
+            RUMError::Io(kind, _, _) => {
+                ERROR_CODE_IO
+                    + match kind {
+                        ErrorKind::NotFound => 1,
+                        ErrorKind::PermissionDenied => 2,
+                        ErrorKind::ConnectionRefused => 3,
+                        ErrorKind::ConnectionReset => 4,
+                        ErrorKind::ConnectionAborted => 5,
+                        ErrorKind::NotConnected => 6,
+                        ErrorKind::AddrInUse => 7,
+                        ErrorKind::AddrNotAvailable => 8,
+                        ErrorKind::BrokenPipe => 9,
+                        ErrorKind::AlreadyExists => 10,
+                        ErrorKind::WouldBlock => 11,
+                        ErrorKind::InvalidInput => 12,
+                        ErrorKind::InvalidData => 13,
+                        ErrorKind::TimedOut => 14,
+                        ErrorKind::WriteZero => 15,
+                        ErrorKind::Interrupted => 16,
+                        ErrorKind::Unsupported => 17,
+                        ErrorKind::UnexpectedEof => 18,
+                        ErrorKind::OutOfMemory => 19,
+                        _ => 0,
+                    }
+            }
+            RUMError::Parse(..) => ERROR_CODE_PARSE,
+            RUMError::Python(..) => ERROR_CODE_PYTHON,
+            RUMError::Cache(..) => ERROR_CODE_CACHE,
+            RUMError::Other(..) => ERROR_CODE_OTHER,
+            RUMError::Context { source, .. } => source.code(),
+        }
+    }
+
+    ///
+    /// Stack trace captured where this error was first built, if any. For a [RUMError::Context]
+    /// chain this is the trace of the innermost cause. Requires the `backtrace` feature. See
+    /// [RUMBacktrace].
+    ///
+    #[cfg(feature = "backtrace")]
+    pub fn backtrace(&self) -> Option<&Backtrace> {
+        let trace = match self {
+            RUMError::Io(_, _, backtrace) => backtrace,
+            RUMError::Parse(_, backtrace) => backtrace,
+            RUMError::Python(_, backtrace) => backtrace,
+            RUMError::Cache(_, backtrace) => backtrace,
+            RUMError::Other(_, backtrace) => backtrace,
+            RUMError::Context {
+                source, backtrace, ..
+            } => return source.backtrace().or(backtrace.trace.as_deref()),
+        };
+        trace.trace.as_deref()
+    }
+}
 
 ///
-/// Type used for propagating error messages.
+/// A [RUMError::Context] prints its context followed by its cause, e.g.
+/// `parsing PID: parsing PID-3: bad`. Use [message](RUMError::message) for the context alone.
 ///
-pub type RUMResult<T> = Result<T, RUMString>;
+impl Display for RUMError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RUMError::Context {
+                context, source, ..
+            } => write!(f, "{}: {}", context, source),
+            _ => f.write_str(self.message()),
+        }
+    }
+}
+
+impl std::error::Error for RUMError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RUMError::Context { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+///
+/// Serializes as `{"code": u32, "kind": str, "message": str, "context": [str]}`. For
+/// [RUMError::Context] chains, `code`, `kind` and `message` describe the underlying cause and
+/// `context` lists the annotations from the outermost in. Requires the `serde` feature.
+///
+#[cfg(feature = "serde")]
+impl serde::Serialize for RUMError {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut context: Vec<&RUMString> = Vec::new();
+        let mut cause = self;
+        while let RUMError::Context {
+            context: annotation,
+            source,
+            ..
+        } = cause
+        {
+            context.push(annotation);
+            cause = source;
+        }
+        let kind = match cause {
+            RUMError::Io(..) => "Io",
+            RUMError::Parse(..) => "Parse",
+            RUMError::Python(..) => "Python",
+            RUMError::Cache(..) => "Cache",
+            RUMError::Other(..) => "Other",
+            RUMError::Context { .. } => "Context",
+        };
+        let mut state = serializer.serialize_struct("RUMError", 4)?;
+        state.serialize_field("code", &cause.code())?;
+        state.serialize_field("kind", kind)?;
+        state.serialize_field("message", cause.message())?;
+        state.serialize_field("context", &context)?;
+        state.end()
+    }
+}
+
+///
+/// Plain messages become [RUMError::Other], so code written against the old string errors keeps
+/// working with `?` and `.into()`.
+///
+impl From<RUMString> for RUMError {
+    fn from(message: RUMString) -> Self {
+        RUMError::other(message)
+    }
+}
+
+impl From<std::io::Error> for RUMError {
+    fn from(e: std::io::Error) -> Self {
+        RUMError::io(e.kind(), format_compact!("{}", e))
+    }
+}
+
+///
+/// Type used for propagating errors.
+///
+pub type RUMResult<T> = Result<T, RUMError>;
+
+///
+/// Convenience methods for [RUMResult].
+///
+pub trait RUMResultExt<T> {
+    ///
+    /// Wrap the error, if any, in a [RUMError::Context] describing what was being attempted. The
+    /// original error is kept as the source. The closure only runs on failure.
+    ///
+    /// # Example
+    /// ```
+    ///  use rumtk_core::core::{RUMError, RUMResult, RUMResultExt};
+    ///  use rumtk_core::strings::RUMString;
+    ///  let result: RUMResult<()> = Err(RUMError::parse(RUMString::from("Missing field separator!")));
+    ///  let error = result.with_context(|| "parsing MSH").unwrap_err();
+    ///  assert_eq!("parsing MSH: Missing field separator!", error.to_string());
+    ///```
+    ///
+    fn with_context<C, F>(self, context: F) -> RUMResult<T>
+    where
+        C: Into<RUMString>,
+        F: FnOnce() -> C;
+
+    ///
+    /// Return the value, or log the error as a warning and return `T::default()`. Use it where a
+    /// failure should be reported but must not interrupt processing. Logging goes through
+    /// [log_warn](crate::log::log_warn), so nothing is printed without the `logging` feature.
+    ///
+    fn or_log(self) -> T
+    where
+        T: Default;
+
+    ///
+    /// Call `f` with a reference to the error, if any, and return the result unchanged. Handy for
+    /// side effects like counting failures.
+    ///
+    fn tap_err<F>(self, f: F) -> RUMResult<T>
+    where
+        F: FnOnce(&RUMError);
+
+    ///
+    /// Replace the message of the error, if any, while keeping its category.
+    ///
+    /// # Example
+    /// ```
+    ///  use rumtk_core::core::{RUMError, RUMResult, RUMResultExt};
+    ///  use rumtk_core::strings::RUMString;
+    ///  use std::io::ErrorKind;
+    ///  let result: RUMResult<()> = Err(RUMError::io(ErrorKind::NotFound, RUMString::from("os error 2")));
+    ///  let error = result.map_err_msg("Configuration file is missing!").unwrap_err();
+    ///  let expected = RUMError::io(ErrorKind::NotFound, RUMString::from("Configuration file is missing!"));
+    ///  assert_eq!(expected, error);
+    ///```
+    ///
+    fn map_err_msg<M>(self, msg: M) -> RUMResult<T>
+    where
+        M: Into<RUMString>;
+}
+
+impl<T> RUMResultExt<T> for RUMResult<T> {
+    fn with_context<C, F>(self, context: F) -> RUMResult<T>
+    where
+        C: Into<RUMString>,
+        F: FnOnce() -> C,
+    {
+        self.map_err(|e| RUMError::Context {
+            context: context().into(),
+            source: Box::new(e),
+            backtrace: RUMBacktrace::capture(),
+        })
+    }
+
+    fn or_log(self) -> T
+    where
+        T: Default,
+    {
+        match self {
+            Ok(value) => value,
+            Err(e) => {
+                log_warn(format_args!("{}", e));
+                T::default()
+            }
+        }
+    }
+
+    fn tap_err<F>(self, f: F) -> RUMResult<T>
+    where
+        F: FnOnce(&RUMError),
+    {
+        if let Err(e) = &self {
+            f(e);
+        }
+        self
+    }
+
+    fn map_err_msg<M>(self, msg: M) -> RUMResult<T>
+    where
+        M: Into<RUMString>,
+    {
+        self.map_err(|e| {
+            let msg = msg.into();
+            match e {
+                RUMError::Io(kind, _, backtrace) => RUMError::Io(kind, msg, backtrace),
+                RUMError::Parse(_, backtrace) => RUMError::Parse(msg, backtrace),
+                RUMError::Python(_, backtrace) => RUMError::Python(msg, backtrace),
+                RUMError::Cache(_, backtrace) => RUMError::Cache(msg, backtrace),
+                RUMError::Other(_, backtrace) => RUMError::Other(msg, backtrace),
+                RUMError::Context {
+                    source, backtrace, ..
+                } => RUMError::Context {
+                    context: msg,
+                    source,
+                    backtrace,
+                },
+            }
+        })
+    }
+}
+
+///
+/// Convenience methods for [Option].
+///
+pub trait OptionExt<T> {
+    ///
+    /// Convert `Some(value)` into `Ok(value)` and `None` into a [RUMError::Other] with `msg`.
+    ///
+    /// # Example
+    /// ```
+    ///  use rumtk_core::core::OptionExt;
+    ///  let fields = vec!["PID", "1"];
+    ///  assert_eq!(Ok(&"1"), fields.get(1).ok_or_msg("PID-1 is missing!"));
+    ///  let error = fields.get(3).ok_or_msg("PID-3 is missing!").unwrap_err();
+    ///  assert_eq!("PID-3 is missing!", error.to_string());
+    ///```
+    ///
+    fn ok_or_msg<M>(self, msg: M) -> RUMResult<T>
+    where
+        M: Into<RUMString>;
+}
+
+impl<T> OptionExt<T> for Option<T> {
+    fn ok_or_msg<M>(self, msg: M) -> RUMResult<T>
+    where
+        M: Into<RUMString>,
+    {
+        match self {
+            Some(value) => Ok(value),
+            None => Err(RUMError::other(msg)),
+        }
+    }
+}
 
 pub type RUMVec<T> = Vec<T>;
 
+///
+/// Run `f`, turning a panic into a [RUMError::Other] carrying the panic message instead of
+/// unwinding further. Use it at boundaries where one bad message must not take the whole service
+/// down.
+///
+/// Caveats:
+/// * `f` must be [UnwindSafe]. Closures capturing `&mut` state or types with interior mutability
+///   are not, because a panic halfway through can leave that state broken. Wrap them in
+///   [AssertUnwindSafe](std::panic::AssertUnwindSafe) only if that state is discarded or
+///   validated after an error.
+/// * Only unwinding panics are caught. Builds with `panic = "abort"` still abort.
+/// * The panic hook still runs, so the panic is printed to stderr as usual.
+///
+/// # Example
+/// ```
+///  use rumtk_core::core::catch_rum;
+///  let result = catch_rum(|| -> u32 { panic!("Segment is malformed!") });
+///  assert!(result.unwrap_err().message().contains("Segment is malformed!"));
+///  assert_eq!(Ok(5), catch_rum(|| 5));
+///```
+///
+pub fn catch_rum<F, R>(f: F) -> RUMResult<R>
+where
+    F: FnOnce() -> R + UnwindSafe,
+{
+    match catch_unwind(f) {
+        Ok(value) => Ok(value),
+        Err(payload) => {
+            let reason = match payload.downcast_ref::<&str>() {
+                Some(reason) => *reason,
+                None => match payload.downcast_ref::<String>() {
+                    Some(reason) => reason.as_str(),
+                    None => "unknown reason",
+                },
+            };
+            Err(RUMError::other(format_compact!(
+                "Operation panicked because {}!",
+                reason
+            )))
+        }
+    }
+}
+
+///
+/// Gather the values of many results, stopping at the first error. The remaining items of the
+/// iterator are not consumed.
+///
+/// # Example
+/// ```
+///  use rumtk_core::core::collect_results;
+///  use rumtk_core::strings::parse_number;
+///  let fields = ["1", "2", "3"];
+///  let values = collect_results(fields.iter().map(|f| parse_number::<u32>(f))).unwrap();
+///  assert_eq!(vec![1, 2, 3], values);
+///```
+///
+pub fn collect_results<T>(iter: impl Iterator<Item = RUMResult<T>>) -> RUMResult<Vec<T>> {
+    iter.collect()
+}
+
+///
+/// Gather the values of many results. Unlike [collect_results], every item is evaluated and all
+/// errors are returned together, which is what field validation wants to report.
+///
+pub fn collect_all_errors<T>(
+    iter: impl Iterator<Item = RUMResult<T>>,
+) -> Result<Vec<T>, Vec<RUMError>> {
+    let mut values = Vec::new();
+    let mut errors = Vec::new();
+    for result in iter {
+        match result {
+            Ok(value) => values.push(value),
+            Err(e) => errors.push(e),
+        }
+    }
+    match errors.is_empty() {
+        true => Ok(values),
+        false => Err(errors),
+    }
+}
+
 pub fn is_unique<T: std::cmp::Eq + std::hash::Hash>(data: &Vec<T>) -> bool {
     let mut keys = ahash::AHashSet::with_capacity(data.len());
     for itm in data {
@@ -94,10 +601,10 @@ pub fn is_unique<T: std::cmp::Eq + std::hash::Hash>(data: &Vec<T>) -> bool {
 pub fn clamp_index(given_indx: &isize, max_size: &isize) -> RUMResult<usize> {
     let neg_max_indx = *max_size * -1;
     if *given_indx == 0 {
-        return Err(format_compact!(
+        return Err(RUMError::other(format_compact!(
             "Index {} is invalid! Use 1-indexed values if using positive indices.",
             given_indx
-        ));
+        )));
     }
 
     if *given_indx >= neg_max_indx && *given_indx < 0 {
@@ -108,10 +615,70 @@ pub fn clamp_index(given_indx: &isize, max_size: &isize) -> RUMResult<usize> {
         return Ok(*given_indx as usize);
     }
 
-    Err(format_compact!(
+    Err(RUMError::other(format_compact!(
         "Index {} is outside {} < x < {} boundary!",
         given_indx,
         neg_max_indx,
         max_size
-    ))
+    )))
+}
+
+pub mod macros {
+    ///
+    /// Evaluate a [RUMResult](crate::core::RUMResult). On success, yield the value. On failure,
+    /// attach the given context to the error (see
+    /// [RUMResultExt::with_context](crate::core::RUMResultExt::with_context)) and return it from
+    /// the enclosing function.
+    ///
+    /// The context can be any expression convertible into a [RUMString](crate::strings::RUMString)
+    /// or a format string with arguments. It is only built on failure.
+    ///
+    /// # Examples
+    /// ## Success
+    /// ```
+    /// use rumtk_core::core::RUMResult;
+    /// use rumtk_core::rumtk_try;
+    /// use rumtk_core::strings::parse_number;
+    ///
+    /// fn parse_set_id(field: &str) -> RUMResult<u32> {
+    ///     let set_id = rumtk_try!(parse_number::<u32>(field), "parsing PID-1");
+    ///     Ok(set_id + 1)
+    /// }
+    ///
+    /// assert_eq!(2, parse_set_id("1").unwrap());
+    /// ```
+    ///
+    /// ## Error
+    /// ```
+    /// use rumtk_core::core::RUMResult;
+    /// use rumtk_core::rumtk_try;
+    /// use rumtk_core::strings::parse_number;
+    ///
+    /// fn parse_field(segment: &str, field: &str) -> RUMResult<u32> {
+    ///     let value = rumtk_try!(parse_number::<u32>(field), "parsing {}-1", segment);
+    ///     Ok(value)
+    /// }
+    ///
+    /// let error = parse_field("PID", "one").unwrap_err();
+    /// assert!(error.to_string().starts_with("parsing PID-1: "), "Got {}!", error);
+    /// ```
+    ///
+    #[macro_export]
+    macro_rules! rumtk_try {
+        ( $result:expr, $context:expr ) => {{
+            use $crate::core::RUMResultExt;
+            match $result.with_context(|| $context) {
+                Ok(value) => value,
+                Err(e) => return Err(e.into()),
+            }
+        }};
+        ( $result:expr, $fmt:literal, $($arg:tt)+ ) => {{
+            use $crate::core::RUMResultExt;
+            use $crate::strings::format_compact;
+            match $result.with_context(|| format_compact!($fmt, $($arg)+)) {
+                Ok(value) => value,
+                Err(e) => return Err(e.into()),
+            }
+        }};
+    }
 }
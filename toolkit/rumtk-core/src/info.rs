@@ -0,0 +1,131 @@
+/*
+ * rumtk attempts to implement HL7 and medical protocols for interoperability in medicine.
+ * This toolkit aims to be reliable, simple, performant, and standards compliant.
+ * Copyright (C) 2025  Luis M. Santos, M.D.
+ *
+ * This library is free software; you can redistribute it and/or
+ * modify it under the terms of the GNU Lesser General Public
+ * License as published by the Free Software Foundation; either
+ * version 2.1 of the License, or (at your option) any later version.
+ *
+ * This library is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+ * Lesser General Public License for more details.
+ *
+ * You should have received a copy of the GNU Lesser General Public
+ * License along with this library; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
+ */
+use crate::core::catch_rum;
+use crate::strings::RUMString;
+use pyo3::Python;
+
+/**************************** Constants**************************************/
+/// Cargo features this build of the toolkit was compiled with.
+pub const ENABLED_FEATURES: &[&str] = &[
+    #[cfg(feature = "backtrace")]
+    "backtrace",
+    #[cfg(feature = "blake3")]
+    "blake3",
+    #[cfg(feature = "collation")]
+    "collation",
+    #[cfg(feature = "logging")]
+    "logging",
+    #[cfg(feature = "serde")]
+    "serde",
+    #[cfg(feature = "unicode-normalization")]
+    "unicode-normalization",
+];
+
+/**************************** Types *****************************************/
+
+///
+/// Identifies the exact build of the toolkit, for diagnostics and protocol negotiation.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionInfo {
+    /// Crate version, e.g. `0.9.4`.
+    pub version: RUMString,
+    /// Short git commit hash the crate was built from, or `unknown` outside a git checkout.
+    pub git_hash: RUMString,
+    /// Enabled Cargo features. See [ENABLED_FEATURES].
+    pub features: Vec<RUMString>,
+}
+
+///
+/// Optional subsystems usable in this process. See [capabilities].
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Capabilities {
+    /// An embedded Python interpreter could be started.
+    pub python_available: bool,
+    /// The `numpy` package can be imported by the embedded interpreter.
+    pub numpy_available: bool,
+    /// Built with the `serde` feature.
+    pub serde_enabled: bool,
+    /// Built with the `logging` feature.
+    pub logging_enabled: bool,
+    /// Built with the `backtrace` feature.
+    pub backtrace_enabled: bool,
+    /// Built with the `collation` feature.
+    pub collation_enabled: bool,
+    /// Built with the `unicode-normalization` feature.
+    pub unicode_normalization_enabled: bool,
+    /// Built with the `blake3` feature.
+    pub blake3_enabled: bool,
+}
+
+/**************************** Helpers ***************************************/
+
+///
+/// Report the version, git commit and enabled features of this build.
+///
+/// # Example
+/// ```
+///  use rumtk_core::info::version_info;
+///  let info = version_info();
+///  println!("rumtk-core {} ({}) features: {:?}", info.version, info.git_hash, info.features);
+///```
+///
+pub fn version_info() -> VersionInfo {
+    VersionInfo {
+        version: RUMString::from(env!("CARGO_PKG_VERSION")),
+        git_hash: RUMString::from(env!("RUMTK_GIT_HASH")),
+        features: ENABLED_FEATURES
+            .iter()
+            .map(|feature| RUMString::from(*feature))
+            .collect(),
+    }
+}
+
+///
+/// Detect which optional subsystems can be used, so callers can branch before invoking them.
+///
+/// Feature flags are known at compile time. Python support is probed at runtime by starting the
+/// interpreter (if needed) and importing `numpy`. A missing or broken interpreter is reported as
+/// unavailable instead of panicking.
+///
+/// # Example
+/// ```
+///  use rumtk_core::info::capabilities;
+///  if !capabilities().numpy_available {
+///      println!("Skipping vectorized transforms.");
+///  }
+///```
+///
+pub fn capabilities() -> Capabilities {
+    let (python_available, numpy_available) =
+        catch_rum(|| Python::attach(|py| (true, py.import("numpy").is_ok())))
+            .unwrap_or((false, false));
+    Capabilities {
+        python_available,
+        numpy_available,
+        serde_enabled: cfg!(feature = "serde"),
+        logging_enabled: cfg!(feature = "logging"),
+        backtrace_enabled: cfg!(feature = "backtrace"),
+        collation_enabled: cfg!(feature = "collation"),
+        unicode_normalization_enabled: cfg!(feature = "unicode-normalization"),
+        blake3_enabled: cfg!(feature = "blake3"),
+    }
+}
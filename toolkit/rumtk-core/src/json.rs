@@ -70,35 +70,37 @@ pub mod serialization {
     #[macro_export]
     macro_rules! rumtk_serialize {
         ( $object:expr ) => {{
+            use $crate::core::RUMError;
             use $crate::json::serialization::{to_string, to_string_pretty};
             use $crate::strings::format_compact;
 
             match to_string(&$object) {
                 Ok(s) => Ok(s),
-                Err(e) => Err(format_compact!(
+                Err(e) => Err(RUMError::parse(format_compact!(
                     "Failed to serialize object because of {}",
                     e
-                )),
+                ))),
             }
         }};
         ( $object:expr, $pretty:expr ) => {{
+            use $crate::core::RUMError;
             use $crate::json::serialization::{to_string, to_string_pretty};
             use $crate::strings::format_compact;
 
             match $pretty {
                 true => match to_string_pretty(&$object) {
                     Ok(s) => Ok(s),
-                    Err(e) => Err(format_compact!(
+                    Err(e) => Err(RUMError::parse(format_compact!(
                         "Failed to serialize object because of {}",
                         e
-                    )),
+                    ))),
                 },
                 false => match to_string(&$object) {
                     Ok(s) => Ok(s),
-                    Err(e) => Err(format_compact!(
+                    Err(e) => Err(RUMError::parse(format_compact!(
                         "Failed to serialize object because of {}",
                         e
-                    )),
+                    ))),
                 },
             }
         }};
@@ -137,9 +139,17 @@ pub mod serialization {
     #[macro_export]
     macro_rules! rumtk_deserialize {
         ( $string:expr ) => {{
+            use $crate::core::RUMError;
             use $crate::json::serialization::from_str;
+            use $crate::strings::format_compact;
 
-            from_str(&$string)
+            match from_str(&$string) {
+                Ok(object) => Ok(object),
+                Err(e) => Err(RUMError::parse(format_compact!(
+                    "Failed to deserialize object because of {}",
+                    e
+                ))),
+            }
         }};
     }
 }
@@ -26,14 +26,18 @@
 
 pub mod cache;
 pub mod cli;
+pub mod config;
 pub mod core;
+pub mod info;
 pub mod json;
 pub mod log;
 pub mod maths;
 pub mod net;
+pub mod profiling;
 pub mod queue;
 pub mod scripting;
 pub mod search;
+pub mod shutdown;
 pub mod strings;
 pub mod threading;
 
@@ -41,8 +45,12 @@ pub mod threading;
 mod tests {
     use super::*;
     use crate::cache::RUMCache;
+    use crate::core::{RUMError, RUMResult, RUMResultExt};
     use crate::search::rumtk_search::*;
-    use crate::strings::{RUMArrayConversions, RUMString, RUMStringConversions, StringUtils};
+    use crate::strings::{
+        AsStr, Hl7TimestampPrecision, RUMArrayConversions, RUMRope, RUMStr, RUMString,
+        RUMStringConversions, RedactionPolicy, StringUtils, UTFStringExtensions,
+    };
     use compact_str::{format_compact, CompactString};
     use serde::{Deserialize, Deserializer, Serialize, Serializer};
     use serde_json::to_string;
@@ -215,6 +223,2812 @@ mod tests {
         println!("Passed!")
     }
 
+    #[test]
+    fn test_trim_control_chars_mllp_framing() {
+        let input = RUMString::from("\x0bMSH|^~\\&|ADT|HOSP\rPID|1||12345\r\x1c\r");
+        let expected = "MSH|^~\\&|ADT|HOSP\rPID|1||12345";
+        let result = strings::trim_control_chars(&input);
+        println!(
+            "Input: {:?} Expected: {:?} Got: {:?}",
+            input, expected, result
+        );
+        assert_eq!(expected, result, "Framing bytes were not trimmed!");
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_trim_control_chars_keep_segment_terminator() {
+        let input = "\x00\x0bMSH|^~\\&|ADT|HOSP\rPID|1||12345\r\x1c\r";
+        let expected = "MSH|^~\\&|ADT|HOSP\rPID|1||12345\r";
+        let result = strings::trim_control_chars_with(input, false, true);
+        println!(
+            "Input: {:?} Expected: {:?} Got: {:?}",
+            input, expected, result
+        );
+        assert_eq!(expected, result, "Segment terminator was not preserved!");
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_trim_control_chars_strip_all() {
+        let input = "\x0bMSH|^~\\&\x00|ADT\rPID|1\r\x0b\x1c\r";
+        let expected_no_cr = "MSH|^~\\&|ADTPID|1";
+        let expected_cr = "MSH|^~\\&|ADT\rPID|1\r";
+        let result_no_cr = strings::trim_control_chars_with(input, true, false);
+        let result_cr = strings::trim_control_chars_with(input, true, true);
+        assert_eq!(
+            expected_no_cr, result_no_cr,
+            "Control characters were not removed!"
+        );
+        assert_eq!(
+            expected_cr, result_cr,
+            "Control characters were not removed!"
+        );
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_trim_control_chars_only_control() {
+        let input = RUMString::from("\x0b\x1c\r");
+        let result = strings::trim_control_chars(&input);
+        assert!(result.is_empty(), "Expected empty string! Got {:?}", result);
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_base64_round_trip() {
+        let input = "I ❤ my wife!\x00\x01\x02".as_bytes();
+        let encoded = strings::base64_encode(input);
+        let decoded = strings::base64_decode(&encoded).unwrap();
+        println!("Input: {:?} Encoded: {} Got: {:?}", input, encoded, decoded);
+        assert_eq!(input, decoded.as_slice(), "Base64 round trip mismatch!");
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_base64_round_trip_empty() {
+        let encoded = strings::base64_encode(&[]);
+        let decoded = strings::base64_decode(&encoded).unwrap();
+        assert!(
+            encoded.is_empty(),
+            "Expected empty encoding! Got {}",
+            encoded
+        );
+        assert!(
+            decoded.is_empty(),
+            "Expected empty decoding! Got {:?}",
+            decoded
+        );
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_base64_decode_malformed() {
+        let input = RUMString::from("SGVsbG8*V29ybGQh!");
+        let result = strings::base64_decode(&input);
+        println!("Input: {} Got: {:?}", input, result);
+        assert!(result.is_err(), "Malformed base64 was decoded!");
+        assert!(
+            result.unwrap_err().message().contains("not valid base64"),
+            "Error message does not describe the failure!"
+        );
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_hex_round_trip() {
+        let input = "I ❤ my wife!\x00\x0b\x1c".as_bytes();
+        let encoded = strings::hex_encode(input);
+        let decoded = strings::hex_decode(&encoded).unwrap();
+        println!("Input: {:?} Encoded: {} Got: {:?}", input, encoded, decoded);
+        assert_eq!(input, decoded.as_slice(), "Hex round trip mismatch!");
+        assert_eq!(
+            encoded,
+            encoded.to_lowercase(),
+            "Hex encoding is not lowercase!"
+        );
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_hex_decode_odd_length() {
+        let input = RUMString::from("0b1");
+        let result = strings::hex_decode(&input);
+        println!("Input: {} Got: {:?}", input, result);
+        assert!(result.is_err(), "Odd length hex string was decoded!");
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_hex_decode_non_hex() {
+        for input in ["0g1c", "+f00", "❤0"] {
+            let result = strings::hex_decode(input);
+            println!("Input: {} Got: {:?}", input, result);
+            assert!(result.is_err(), "Non hex string {} was decoded!", input);
+        }
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_rumstr_field_extraction() {
+        let message = RUMString::from("PID|1||12345^^^HOSP||DOE^JOHN");
+        let view = RUMStr::from(&message);
+        let fields: Vec<RUMStr> = view.split("|").collect();
+        println!("Input: {:?} Got: {:?}", message, fields);
+        assert_eq!(fields.len(), 6, "Incorrect number of fields!");
+        assert!(fields[0].starts_with("PID"), "Segment id mismatch!");
+        assert_eq!(fields[3], "12345^^^HOSP", "Field mismatch!");
+        assert_eq!(fields[2].len(), 0, "Expected empty field!");
+        assert!(fields[2].is_empty(), "Expected empty field!");
+
+        // The views must point into the parent buffer rather than into new allocations.
+        let parent_range = message.as_str().as_bytes().as_ptr_range();
+        for field in fields.iter() {
+            let ptr = field.as_str().as_ptr();
+            assert!(
+                parent_range.start <= ptr && ptr <= parent_range.end,
+                "Field {} was not borrowed from the parent string!",
+                field
+            );
+        }
+
+        let name = view.split_nth("|", 5).unwrap();
+        let family = name.split_nth("^", 0).unwrap();
+        assert_eq!(family, "DOE", "Component mismatch!");
+
+        let owned: RUMString = family.to_owned();
+        assert_eq!(owned, "DOE", "Owned copy mismatch!");
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_join_hl7_fields() {
+        let fields = vec![
+            RUMString::from("PID"),
+            RUMString::from("1"),
+            RUMString::from(""),
+            RUMString::from("12345^^^HOSP"),
+            RUMString::from(""),
+        ];
+        let expected = "PID|1||12345^^^HOSP|";
+        let result = strings::join_hl7(&fields, '|');
+        println!(
+            "Input: {:?} Expected: {:?} Got: {:?}",
+            fields, expected, result
+        );
+        assert_eq!(expected, result, "Fields were not joined correctly!");
+        assert_eq!(
+            expected.len(),
+            result.len(),
+            "Joined string has the wrong length!"
+        );
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_join_hl7_components() {
+        let components = vec![
+            RUMString::from("DOE"),
+            RUMString::from("JOHN"),
+            RUMString::from(""),
+            RUMString::from("JR"),
+        ];
+        let expected = "DOE^JOHN^^JR";
+        let result = strings::join_hl7(&components, '^');
+        println!(
+            "Input: {:?} Expected: {:?} Got: {:?}",
+            components, expected, result
+        );
+        assert_eq!(expected, result, "Components were not joined correctly!");
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_rum_join_edge_cases() {
+        let empty: Vec<RUMString> = vec![];
+        assert_eq!(strings::rum_join(&empty, "|"), "", "Expected empty string!");
+        let single = vec![RUMString::from("MSH")];
+        assert_eq!(
+            strings::rum_join(&single, "|"),
+            "MSH",
+            "Single part mismatch!"
+        );
+        let blanks = vec![RUMString::default(); 3];
+        assert_eq!(
+            strings::rum_join(&blanks, "~"),
+            "~~",
+            "Empty parts mismatch!"
+        );
+        let multi = vec![RUMString::from("a"), RUMString::from("b")];
+        assert_eq!(
+            strings::rum_join(&multi, "\r\n"),
+            "a\r\nb",
+            "Separator mismatch!"
+        );
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_parse_hl7_ts_precisions() {
+        let cases = [
+            ("2024", Hl7TimestampPrecision::Year),
+            ("202401", Hl7TimestampPrecision::Month),
+            ("20240115", Hl7TimestampPrecision::Day),
+            ("2024011512", Hl7TimestampPrecision::Hour),
+            ("202401151230", Hl7TimestampPrecision::Minute),
+            ("20240115123045", Hl7TimestampPrecision::Second),
+            ("20240115123045.1", Hl7TimestampPrecision::Fraction(1)),
+            ("20240115123045.1234", Hl7TimestampPrecision::Fraction(4)),
+        ];
+        for (input, expected) in cases {
+            let ts = strings::parse_hl7_ts(input).unwrap();
+            println!("Input: {} Expected: {:?} Got: {:?}", input, expected, ts);
+            assert_eq!(ts.precision, expected, "Precision mismatch for {}!", input);
+            assert_eq!(ts.year, 2024, "Year mismatch for {}!", input);
+            assert_eq!(ts.offset, None, "Unexpected offset for {}!", input);
+            let formatted = strings::format_hl7_ts(&ts, ts.precision);
+            assert_eq!(formatted, input, "Round trip mismatch for {}!", input);
+        }
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_parse_hl7_ts_timezone() {
+        let input = RUMString::from("20240115123045.123-0500");
+        let ts = strings::parse_hl7_ts(&input).unwrap();
+        println!("Input: {} Got: {:?}", input, ts);
+        assert_eq!(
+            (ts.year, ts.month, ts.day, ts.hour, ts.minute, ts.second),
+            (2024, 1, 15, 12, 30, 45),
+            "Date time components mismatch!"
+        );
+        assert_eq!(ts.fraction, 1230, "Fraction mismatch!");
+        assert_eq!(ts.offset, Some(-300), "Offset mismatch!");
+        assert_eq!(
+            strings::format_hl7_ts(&ts, ts.precision),
+            input,
+            "Round trip mismatch!"
+        );
+        assert_eq!(
+            strings::format_hl7_ts(&ts, Hl7TimestampPrecision::Minute),
+            "202401151230-0500",
+            "Truncated format mismatch!"
+        );
+        let positive = strings::parse_hl7_ts("202401151230+0530").unwrap();
+        assert_eq!(positive.offset, Some(330), "Offset mismatch!");
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_parse_hl7_ts_invalid() {
+        let inputs = [
+            "",
+            "20",
+            "2024011",
+            "2024AB15",
+            "20241315",
+            "20230229",
+            "20240115250000",
+            "202401151230.12",
+            "20240115123045.12345",
+            "20240115123045-05",
+            "20240115123045+05X0",
+        ];
+        for input in inputs {
+            let result = strings::parse_hl7_ts(input);
+            println!("Input: {:?} Got: {:?}", input, result);
+            assert!(result.is_err(), "Invalid timestamp {:?} was parsed!", input);
+        }
+        assert!(
+            strings::parse_hl7_ts("20240229").is_ok(),
+            "Leap day was rejected!"
+        );
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_parse_number_integers() {
+        let value: i64 = strings::parse_number(&RUMString::from("  -12345\t")).unwrap();
+        assert_eq!(value, -12345, "Integer mismatch!");
+        let value: u8 = strings::parse_number("255").unwrap();
+        assert_eq!(value, 255, "Integer mismatch!");
+        let overflow = strings::parse_number::<u8>("256");
+        assert!(overflow.is_err(), "Overflowing integer was parsed!");
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_parse_number_floats() {
+        let value: f64 = strings::parse_number(" 98.6 ").unwrap();
+        assert_eq!(value, 98.6, "Float mismatch!");
+        let value: f32 = strings::parse_number("1e3").unwrap();
+        assert_eq!(value, 1000.0, "Float mismatch!");
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_parse_number_empty_fields() {
+        for input in ["", "   ", "\"\"", " \"\" "] {
+            let strict = strings::parse_number::<i32>(input);
+            let optional = strings::parse_number_opt::<i32>(input).unwrap();
+            println!(
+                "Input: {:?} Strict: {:?} Optional: {:?}",
+                input, strict, optional
+            );
+            assert!(strict.is_err(), "Empty field {:?} was parsed!", input);
+            assert_eq!(optional, None, "Empty field {:?} was not None!", input);
+        }
+        let optional = strings::parse_number_opt::<i32>(" 7 ").unwrap();
+        assert_eq!(optional, Some(7), "Optional parse mismatch!");
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_parse_number_garbage() {
+        for input in ["abc", "12abc", "1.2.3", "--1", "1 2"] {
+            let strict = strings::parse_number::<f64>(input);
+            let optional = strings::parse_number_opt::<f64>(input);
+            println!(
+                "Input: {:?} Strict: {:?} Optional: {:?}",
+                input, strict, optional
+            );
+            assert!(strict.is_err(), "Garbage {:?} was parsed!", input);
+            assert!(optional.is_err(), "Garbage {:?} was parsed!", input);
+        }
+        println!("Passed!")
+    }
+
+    fn template_vars() -> RUMCache<RUMString, RUMString> {
+        let mut vars = RUMCache::<RUMString, RUMString>::default();
+        vars.insert(RUMString::from("mrn"), RUMString::from("12345"));
+        vars.insert(RUMString::from("family"), RUMString::from("DOE"));
+        vars.insert(RUMString::from("given"), RUMString::from("JOHN"));
+        vars
+    }
+
+    #[test]
+    fn test_render_template_substitution() {
+        let template = RUMString::from("PID|1||{{mrn}}^^^HOSP||{{ family }}^{{given}}");
+        let expected = "PID|1||12345^^^HOSP||DOE^JOHN";
+        let result = strings::render_template(&template, &template_vars()).unwrap();
+        println!(
+            "Input: {:?} Expected: {:?} Got: {:?}",
+            template, expected, result
+        );
+        assert_eq!(expected, result, "Template was not rendered correctly!");
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_render_template_missing_key() {
+        let template = RUMString::from("PID|1||{{mrn}}||{{dob}}");
+        let strict = strings::render_template(&template, &template_vars());
+        println!("Input: {:?} Got: {:?}", template, strict);
+        assert!(strict.is_err(), "Missing key did not produce an error!");
+        assert!(
+            strict.unwrap_err().message().contains("dob"),
+            "Error does not name the missing key!"
+        );
+        let lenient = strings::render_template_with(&template, &template_vars(), true).unwrap();
+        assert_eq!(
+            lenient, "PID|1||12345||{{dob}}",
+            "Missing placeholder was not left as is!"
+        );
+        let unterminated = strings::render_template("PID|{{mrn", &template_vars());
+        assert!(
+            unterminated.is_err(),
+            "Unterminated placeholder was rendered!"
+        );
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_render_template_escaping() {
+        let template = RUMString::from("\\{\\{mrn\\}\\} = {{mrn}} ❤");
+        let expected = "{{mrn}} = 12345 ❤";
+        let result = strings::render_template(&template, &template_vars()).unwrap();
+        println!(
+            "Input: {:?} Expected: {:?} Got: {:?}",
+            template, expected, result
+        );
+        assert_eq!(expected, result, "Escaped braces were not passed through!");
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_levenshtein_known_distances() {
+        let cases = [
+            ("kitten", "sitting", 3),
+            ("flaw", "lawn", 2),
+            ("SMITH", "SMYTH", 1),
+            ("JOHN", "JOHNN", 1),
+            ("JOHNSON", "JONSON", 1),
+            ("José", "Jose", 1),
+            ("abc", "xyz", 3),
+        ];
+        for (a, b, expected) in cases {
+            let result = strings::levenshtein(a, b);
+            println!("Input: {} {} Expected: {} Got: {}", a, b, expected, result);
+            assert_eq!(expected, result, "Distance mismatch for {} vs {}!", a, b);
+            assert_eq!(
+                strings::levenshtein(b, a),
+                result,
+                "Distance is not symmetric!"
+            );
+        }
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_levenshtein_identical_and_empty() {
+        assert_eq!(strings::levenshtein("DOE", "DOE"), 0, "Identical mismatch!");
+        assert_eq!(strings::levenshtein("", ""), 0, "Empty mismatch!");
+        assert_eq!(strings::levenshtein("DOE", ""), 3, "Empty mismatch!");
+        assert_eq!(strings::levenshtein("", "DOE"), 3, "Empty mismatch!");
+        assert_eq!(
+            strings::similarity("DOE", "DOE"),
+            1.0,
+            "Similarity mismatch!"
+        );
+        assert_eq!(strings::similarity("", ""), 1.0, "Similarity mismatch!");
+        assert_eq!(strings::similarity("DOE", ""), 0.0, "Similarity mismatch!");
+        assert_eq!(
+            strings::similarity("SMITH", "SMYTH"),
+            0.8,
+            "Similarity mismatch!"
+        );
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_redact_mrn() {
+        let mrn = RUMString::from("MRN-00123456");
+        let full = strings::redact(&mrn, RedactionPolicy::Full);
+        let last_four = strings::redact(&mrn, RedactionPolicy::KeepLast(4));
+        let first_last = strings::redact(&mrn, RedactionPolicy::KeepFirstLast);
+        println!("Input: {} Got: {} {} {}", mrn, full, last_four, first_last);
+        assert_eq!(full, "***-********", "Full redaction mismatch!");
+        assert_eq!(last_four, "***-****3456", "Last four redaction mismatch!");
+        assert_eq!(first_last, "M*N-0******6", "First/last redaction mismatch!");
+        let short = strings::redact("12", RedactionPolicy::KeepLast(4));
+        assert_eq!(short, "12", "Short value redaction mismatch!");
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_redact_name() {
+        let name = RUMString::from("DOE^JOHN^Q^JR");
+        let full = strings::redact(&name, RedactionPolicy::Full);
+        let first_last = strings::redact(&name, RedactionPolicy::KeepFirstLast);
+        println!("Input: {} Got: {} {}", name, full, first_last);
+        assert_eq!(full, "***^****^*^**", "Full redaction mismatch!");
+        assert_eq!(
+            first_last, "D*E^J**N^*^**",
+            "First/last redaction mismatch!"
+        );
+        let unicode = strings::redact("José Núñez", RedactionPolicy::KeepFirstLast);
+        assert_eq!(unicode, "J**é N***z", "Unicode redaction mismatch!");
+        assert_eq!(
+            RedactionPolicy::default(),
+            RedactionPolicy::Full,
+            "Default policy should mask everything!"
+        );
+        println!("Passed!")
+    }
+
+    #[cfg(feature = "unicode-normalization")]
+    #[test]
+    fn test_normalize_unicode_composed_decomposed() {
+        use crate::strings::NormalizationForm;
+        let composed = RUMString::from("Jos\u{e9} N\u{fa}\u{f1}ez");
+        let decomposed = RUMString::from("Jose\u{301} Nu\u{301}n\u{303}ez");
+        assert_ne!(composed, decomposed, "Inputs should differ byte wise!");
+        let composed_nfc = strings::normalize_unicode(&composed, NormalizationForm::NFC);
+        let decomposed_nfc = strings::normalize_unicode(&decomposed, NormalizationForm::NFC);
+        println!(
+            "Input: {:?} {:?} Got: {:?} {:?}",
+            composed, decomposed, composed_nfc, decomposed_nfc
+        );
+        assert_eq!(composed_nfc, decomposed_nfc, "NFC forms do not match!");
+        assert_eq!(composed_nfc, composed, "NFC form should be composed!");
+        let composed_nfd = strings::normalize_unicode(&composed, NormalizationForm::NFD);
+        assert_eq!(composed_nfd, decomposed, "NFD form should be decomposed!");
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_truncate_graphemes_ascii() {
+        let input = RUMString::from("DOE^JOHN");
+        assert_eq!(
+            strings::truncate_graphemes(&input, 3),
+            "DOE",
+            "Truncation mismatch!"
+        );
+        assert_eq!(
+            strings::truncate_graphemes(&input, 8),
+            "DOE^JOHN",
+            "Exact width mismatch!"
+        );
+        assert_eq!(
+            strings::truncate_graphemes(&input, 20),
+            "DOE^JOHN",
+            "Over width mismatch!"
+        );
+        assert_eq!(
+            strings::truncate_graphemes(&input, 0),
+            "",
+            "Zero width mismatch!"
+        );
+        assert_eq!(
+            strings::truncate_graphemes_ellipsis(&input, 4),
+            "DOE…",
+            "Ellipsis truncation mismatch!"
+        );
+        assert_eq!(
+            strings::truncate_graphemes_ellipsis(&input, 8),
+            "DOE^JOHN",
+            "Ellipsis should not be added when nothing is truncated!"
+        );
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_truncate_graphemes_combining_characters() {
+        let input = RUMString::from("Nu\u{301}n\u{303}ez");
+        let result = strings::truncate_graphemes(&input, 2);
+        println!("Input: {:?} Got: {:?}", input, result);
+        assert_eq!(
+            result, "Nu\u{301}",
+            "Combining accent was split from its base!"
+        );
+        let result = strings::truncate_graphemes(&input, 3);
+        assert_eq!(
+            result, "Nu\u{301}n\u{303}",
+            "Combining tilde was split from its base!"
+        );
+        let result = strings::truncate_graphemes_ellipsis(&input, 3);
+        assert_eq!(result, "Nu\u{301}…", "Ellipsis truncation mismatch!");
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_truncate_graphemes_emoji() {
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        let flag = "\u{1F1FA}\u{1F1F8}";
+        let input = format_compact!("I{}{}❤", family, flag);
+        let result = strings::truncate_graphemes(&input, 2);
+        println!("Input: {:?} Got: {:?}", input, result);
+        assert_eq!(
+            result,
+            format_compact!("I{}", family),
+            "Emoji sequence was split!"
+        );
+        let result = strings::truncate_graphemes(&input, 3);
+        assert_eq!(
+            result,
+            format_compact!("I{}{}", family, flag),
+            "Flag was split!"
+        );
+        assert_eq!(result.count_graphemes(), 3, "Grapheme count mismatch!");
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_word_wrap_text() {
+        let input = RUMString::from("The quick brown fox jumps over the lazy dog");
+        let expected = vec!["The quick", "brown fox", "jumps over", "the lazy", "dog"];
+        let result = strings::word_wrap(&input, 10);
+        println!(
+            "Input: {:?} Expected: {:?} Got: {:?}",
+            input, expected, result
+        );
+        assert_eq!(expected, result, "Wrapped lines mismatch!");
+        for line in result.iter() {
+            assert!(
+                line.count_graphemes() <= 10,
+                "Line {:?} exceeds width!",
+                line
+            );
+        }
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_word_wrap_long_word() {
+        let input = RUMString::from("See pneumonoultramicroscopic findings");
+        let expected = vec!["See", "pneumonoul", "tramicrosc", "opic", "findings"];
+        let result = strings::word_wrap(&input, 10);
+        println!(
+            "Input: {:?} Expected: {:?} Got: {:?}",
+            input, expected, result
+        );
+        assert_eq!(expected, result, "Long word was not hard split!");
+        let result = strings::word_wrap("abcdefgh ij", 4);
+        assert_eq!(result, vec!["abcd", "efgh", "ij"], "Hard split mismatch!");
+        let result = strings::word_wrap("abcdef g", 4);
+        assert_eq!(
+            result,
+            vec!["abcd", "ef g"],
+            "Hard split remainder mismatch!"
+        );
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_word_wrap_embedded_newlines() {
+        let input = RUMString::from("Line one is long\r\n\nLine   two");
+        let expected = vec!["Line one", "is long", "", "Line two"];
+        let result = strings::word_wrap(&input, 8);
+        println!(
+            "Input: {:?} Expected: {:?} Got: {:?}",
+            input, expected, result
+        );
+        assert_eq!(expected, result, "Newlines were not respected!");
+        let result = strings::word_wrap("a b\nc", 0);
+        assert_eq!(result, vec!["a b", "c"], "Zero width mismatch!");
+        assert_eq!(strings::word_wrap("", 5), vec![""], "Empty input mismatch!");
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_replace_all_ignore_case_mixed() {
+        let input = RUMString::from("Obx|1|ST|oBx note about OBX ❤ obx");
+        let expected = "NTE|1|ST|NTE note about NTE ❤ NTE";
+        let result = strings::replace_all_ignore_case(&input, "obx", "NTE");
+        println!(
+            "Input: {:?} Expected: {:?} Got: {:?}",
+            input, expected, result
+        );
+        assert_eq!(expected, result, "Case insensitive replace mismatch!");
+        let result = strings::replace_all_ignore_case("Café CAFÉ", "café", "tea");
+        assert_eq!(result, "tea CAFÉ", "Only ASCII letters should ignore case!");
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_replace_all_ignore_case_overlapping() {
+        assert_eq!(
+            strings::replace_all_ignore_case("aAa", "AA", "b"),
+            "ba",
+            "Overlapping matches mismatch!"
+        );
+        assert_eq!(
+            strings::replace_all_ignore_case("AbAB", "ab", "-"),
+            "--",
+            "Adjacent matches mismatch!"
+        );
+        assert_eq!(
+            strings::replace_all_ignore_case("abab", "AB", "ab ab"),
+            "ab abab ab",
+            "Replacement should not be rescanned!"
+        );
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_replace_all_ignore_case_no_matches() {
+        let input = RUMString::from("PID|1||12345");
+        let result = strings::replace_all_ignore_case(&input, "obx", "NTE");
+        assert_eq!(result, input, "Input without matches was modified!");
+        let result = strings::replace_all_ignore_case(&input, "", "NTE");
+        assert_eq!(result, input, "Empty pattern modified the input!");
+        let result = strings::replace_all_ignore_case("ab", "abc", "x");
+        assert_eq!(
+            result, "ab",
+            "Pattern longer than input modified the input!"
+        );
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_starts_with_ignore_case() {
+        let segment = RUMString::from("pid|1||12345");
+        assert!(
+            segment.starts_with_ignore_case("PID"),
+            "Prefix did not match!"
+        );
+        assert!(
+            segment.starts_with_ignore_case("pId|"),
+            "Prefix did not match!"
+        );
+        assert!(
+            segment.starts_with_ignore_case(""),
+            "Empty prefix did not match!"
+        );
+        assert!(
+            !segment.starts_with_ignore_case("OBX"),
+            "Wrong prefix matched!"
+        );
+        assert!(
+            !"PI".starts_with_ignore_case("PID"),
+            "Prefix longer than the string matched!"
+        );
+        assert!(
+            "❤PID".starts_with_ignore_case("❤pid"),
+            "Unicode prefix did not match!"
+        );
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_ends_with_ignore_case() {
+        let file = RUMString::from("batch_0001.HL7");
+        assert!(file.ends_with_ignore_case(".hl7"), "Suffix did not match!");
+        assert!(file.ends_with_ignore_case(".Hl7"), "Suffix did not match!");
+        assert!(
+            file.ends_with_ignore_case(""),
+            "Empty suffix did not match!"
+        );
+        assert!(
+            !file.ends_with_ignore_case(".json"),
+            "Wrong suffix matched!"
+        );
+        assert!(
+            !"HL7".ends_with_ignore_case(".hl7"),
+            "Suffix longer than the string matched!"
+        );
+        assert!(
+            !"é".ends_with_ignore_case("\u{a9}"),
+            "Partial character matched!"
+        );
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_contains_any_hit() {
+        let segment = RUMString::from("OBX|1|TX|||Result is HIGH");
+        assert!(
+            strings::contains_any(&segment, &["LOW", "HIGH", "CRITICAL"]),
+            "Keyword was not found!"
+        );
+        let found = strings::find_any(&segment, &["HIGH", "|", "TX"]);
+        assert_eq!(found, Some((3, 1)), "Earliest match mismatch!");
+        let found = strings::find_any(&segment, &["TX", "TX|"]);
+        assert_eq!(found, Some((6, 0)), "Tie should go to the first needle!");
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_contains_any_miss() {
+        let segment = RUMString::from("PID|1||12345");
+        assert!(
+            !strings::contains_any(&segment, &["^", "~", "\\"]),
+            "Missing needle was found!"
+        );
+        assert_eq!(
+            strings::find_any(&segment, &["^", "~"]),
+            None,
+            "Missing needle was found!"
+        );
+        assert!(
+            !strings::contains_any(&segment, &[""]),
+            "Empty needle should be ignored!"
+        );
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_contains_any_empty_needles() {
+        let segment = RUMString::from("PID|1||12345");
+        assert!(
+            !strings::contains_any(&segment, &[]),
+            "Empty needle list matched!"
+        );
+        assert_eq!(
+            strings::find_any(&segment, &[]),
+            None,
+            "Empty needle list matched!"
+        );
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_split_keep_delimiter_round_trip() {
+        let inputs = [
+            "MSH|^~\\&|ADT\rPID|1||12345\rOBX|1|ST|❤\r",
+            "MSH|^~\\&|ADT\rPID|1||12345",
+            "\r\rPID\r",
+            "PID",
+            "",
+        ];
+        for input in inputs {
+            let pieces = strings::split_keep_delimiter(input, '\r');
+            println!("Input: {:?} Got: {:?}", input, pieces);
+            assert_eq!(
+                pieces.concat(),
+                input,
+                "Concatenation does not match input!"
+            );
+            for piece in pieces.iter().rev().skip(1) {
+                assert!(
+                    piece.ends_with('\r'),
+                    "Piece {:?} lost its delimiter!",
+                    piece
+                );
+            }
+        }
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_split_keep_delimiter_pieces() {
+        let input = RUMString::from("DOE^JOHN^^JR");
+        let expected = vec!["DOE^", "JOHN^", "^", "JR"];
+        let result = strings::split_keep_delimiter(&input, '^');
+        assert_eq!(expected, result, "Pieces mismatch!");
+        let result = strings::split_keep_delimiter("a❤b❤", '❤');
+        assert_eq!(result, vec!["a❤", "b❤"], "Multibyte delimiter mismatch!");
+        assert!(
+            strings::split_keep_delimiter("", '^').is_empty(),
+            "Empty input should produce no pieces!"
+        );
+        println!("Passed!")
+    }
+
+    ///
+    /// Reader handing out at most 3 bytes per read so records straddle the internal buffer.
+    ///
+    struct TrickleReader {
+        data: Vec<u8>,
+        offset: usize,
+    }
+
+    impl std::io::Read for TrickleReader {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let end = (self.offset + 3)
+                .min(self.data.len())
+                .min(self.offset + buf.len());
+            let n = end - self.offset;
+            buf[..n].copy_from_slice(&self.data[self.offset..end]);
+            self.offset = end;
+            Ok(n)
+        }
+    }
+
+    #[test]
+    fn test_read_rumstrings_multi_record_stream() {
+        let batch = "MSH|^~\\&|ADT\rPID|1||12345^^^HOSP\r\rOBX|1|ST|❤";
+        let reader = TrickleReader {
+            data: batch.as_bytes().to_vec(),
+            offset: 0,
+        };
+        let expected = vec!["MSH|^~\\&|ADT", "PID|1||12345^^^HOSP", "", "OBX|1|ST|❤"];
+        let result: Vec<RUMString> =
+            strings::read_rumstrings(reader, strings::DEFAULT_RECORD_TERMINATOR)
+                .map(|record| record.unwrap())
+                .collect();
+        println!(
+            "Input: {:?} Expected: {:?} Got: {:?}",
+            batch, expected, result
+        );
+        assert_eq!(expected, result, "Streamed records mismatch!");
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_read_rumstrings_custom_terminator() {
+        let batch = "first\nsecond\n".as_bytes();
+        let result: Vec<RUMString> = strings::read_rumstrings(batch, b'\n')
+            .map(|record| record.unwrap())
+            .collect();
+        assert_eq!(
+            result,
+            vec!["first", "second"],
+            "Streamed records mismatch!"
+        );
+        let empty: Vec<RUMResult<RUMString>> =
+            strings::read_rumstrings("".as_bytes(), b'\n').collect();
+        assert!(empty.is_empty(), "Empty stream yielded records!");
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_read_rumstrings_invalid_utf8() {
+        let batch: &[u8] = b"MSH\r\xff\xfe\rPID\r";
+        let result: Vec<RUMResult<RUMString>> =
+            strings::read_rumstrings(batch, strings::DEFAULT_RECORD_TERMINATOR).collect();
+        println!("Got: {:?}", result);
+        assert_eq!(result.len(), 3, "Record count mismatch!");
+        assert!(result[1].is_err(), "Invalid UTF-8 record was accepted!");
+        assert_eq!(
+            result[2].as_ref().unwrap(),
+            "PID",
+            "Stream did not recover!"
+        );
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_chunk_ascii() {
+        let input = RUMString::from("PID|1||12345");
+        let expected = vec!["PID|1", "||123", "45"];
+        let result = strings::chunk(&input, 5);
+        println!(
+            "Input: {:?} Expected: {:?} Got: {:?}",
+            input, expected, result
+        );
+        assert_eq!(expected, result, "Chunks mismatch!");
+        assert_eq!(
+            strings::chunk(&input, 12),
+            vec![input.clone()],
+            "Exact size mismatch!"
+        );
+        assert_eq!(
+            strings::chunk(&input, 0),
+            vec![input.clone()],
+            "Zero size mismatch!"
+        );
+        assert!(
+            strings::chunk("", 5).is_empty(),
+            "Empty input produced chunks!"
+        );
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_chunk_multibyte() {
+        let input = RUMString::from("añ❤😀b");
+        for size in 1..=8 {
+            let result = strings::chunk(&input, size);
+            println!("Size: {} Got: {:?}", size, result);
+            assert_eq!(result.concat(), input, "Chunks do not reproduce the input!");
+            for piece in result.iter() {
+                let single_char = piece.chars().count() == 1;
+                assert!(
+                    piece.len() <= size || single_char,
+                    "Chunk {:?} exceeds {} bytes!",
+                    piece,
+                    size
+                );
+            }
+        }
+        let result = strings::chunk(&input, 4);
+        assert_eq!(result, vec!["añ", "❤", "😀", "b"], "Chunks mismatch!");
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_pad_strings() {
+        let mrn = RUMString::from("12345");
+        assert_eq!(
+            strings::pad_left(&mrn, 8, '0'),
+            "00012345",
+            "Left padding mismatch!"
+        );
+        assert_eq!(
+            strings::pad_right(&mrn, 8, ' '),
+            "12345   ",
+            "Right padding mismatch!"
+        );
+        assert_eq!(
+            strings::pad_left("", 3, '*'),
+            "***",
+            "Empty padding mismatch!"
+        );
+        let name = RUMString::from("Núñez");
+        assert_eq!(
+            strings::pad_right(&name, 7, '.'),
+            "Núñez..",
+            "Unicode padding mismatch!"
+        );
+        assert_eq!(
+            strings::pad_left(&name, 6, '❤'),
+            "❤Núñez",
+            "Unicode fill mismatch!"
+        );
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_pad_exact_and_over_width() {
+        let mrn = RUMString::from("12345");
+        assert_eq!(
+            strings::pad_left(&mrn, 5, '0'),
+            mrn,
+            "Exact width was modified!"
+        );
+        assert_eq!(
+            strings::pad_right(&mrn, 5, '0'),
+            mrn,
+            "Exact width was modified!"
+        );
+        assert_eq!(
+            strings::pad_left(&mrn, 3, '0'),
+            mrn,
+            "Over width was modified!"
+        );
+        assert_eq!(
+            strings::pad_right(&mrn, 0, '0'),
+            mrn,
+            "Over width was modified!"
+        );
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_format_thousands_integers() {
+        let cases = [
+            (0, "0"),
+            (999, "999"),
+            (1000, "1,000"),
+            (123456, "123,456"),
+            (1234567, "1,234,567"),
+            (-999, "-999"),
+            (-1000, "-1,000"),
+            (-1234567, "-1,234,567"),
+            (i64::MAX, "9,223,372,036,854,775,807"),
+            (i64::MIN, "-9,223,372,036,854,775,808"),
+        ];
+        for (input, expected) in cases {
+            let result = strings::format_thousands(input, ',');
+            println!("Input: {} Expected: {} Got: {}", input, expected, result);
+            assert_eq!(expected, result, "Formatting mismatch for {}!", input);
+        }
+        assert_eq!(
+            strings::format_thousands(1234567, '.'),
+            "1.234.567",
+            "Custom separator mismatch!"
+        );
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_format_thousands_floats() {
+        let cases = [
+            (0.0, 2, "0.00"),
+            (1234.5, 2, "1,234.50"),
+            (1234567.891, 2, "1,234,567.89"),
+            (999.999, 2, "1,000.00"),
+            (-1234.5, 1, "-1,234.5"),
+            (-0.001, 2, "0.00"),
+            (1234567.6, 0, "1,234,568"),
+        ];
+        for (input, decimals, expected) in cases {
+            let result = strings::format_thousands_f64(input, ',', decimals);
+            println!("Input: {} Expected: {} Got: {}", input, expected, result);
+            assert_eq!(expected, result, "Formatting mismatch for {}!", input);
+        }
+        assert_eq!(
+            strings::format_thousands_f64(f64::NAN, ',', 2),
+            "NaN",
+            "NaN mismatch!"
+        );
+        println!("Passed!")
+    }
+
+    #[cfg(feature = "collation")]
+    #[test]
+    fn test_sort_key_accented_names() {
+        use crate::strings::{locale, sort_key};
+        let mut names: Vec<RUMString> = [
+            "Zoe", "Ñúñez", "Oscar", "Álvarez", "Nuñez", "alvarez", "Nunez",
+        ]
+        .iter()
+        .map(|name| RUMString::from(*name))
+        .collect();
+        let mut byte_sorted = names.clone();
+        byte_sorted.sort();
+        names.sort_by_cached_key(|name| sort_key(name, &locale!("es")));
+        let expected = vec![
+            "alvarez", "Álvarez", "Nunez", "Nuñez", "Ñúñez", "Oscar", "Zoe",
+        ];
+        println!("Byte order: {:?} Locale order: {:?}", byte_sorted, names);
+        assert_ne!(
+            byte_sorted, expected,
+            "Byte order should not match locale order!"
+        );
+        assert_eq!(names, expected, "Names were not sorted in Spanish order!");
+        println!("Passed!")
+    }
+
+    #[cfg(feature = "collation")]
+    #[test]
+    fn test_sort_key_locale_specific_order() {
+        use crate::strings::{locale, sort_key};
+        let names = vec![
+            RUMString::from("Zorn"),
+            RUMString::from("Östberg"),
+            RUMString::from("Olsson"),
+        ];
+        let mut german = names.clone();
+        german.sort_by_cached_key(|name| sort_key(name, &locale!("de")));
+        let mut swedish = names.clone();
+        swedish.sort_by_cached_key(|name| sort_key(name, &locale!("sv")));
+        println!("German: {:?} Swedish: {:?}", german, swedish);
+        assert_eq!(
+            german,
+            vec!["Olsson", "Östberg", "Zorn"],
+            "German order mismatch!"
+        );
+        assert_eq!(
+            swedish,
+            vec!["Olsson", "Zorn", "Östberg"],
+            "Swedish order mismatch!"
+        );
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_jaro_winkler_known_scores() {
+        let cases = [
+            ("MARTHA", "MARHTA", 0.961),
+            ("DWAYNE", "DUANE", 0.840),
+            ("DIXON", "DICKSONX", 0.813),
+            ("SMITH", "SMYTH", 0.893),
+            ("JOHNSON", "XYZ", 0.0),
+        ];
+        for (a, b, expected) in cases {
+            let result = strings::jaro_winkler(a, b);
+            println!("Input: {} {} Expected: {} Got: {}", a, b, expected, result);
+            assert!(
+                (expected - result).abs() < 0.001,
+                "Score mismatch for {} vs {}!",
+                a,
+                b
+            );
+            assert_eq!(
+                result,
+                strings::jaro_winkler(b, a),
+                "Score is not symmetric for {} vs {}!",
+                a,
+                b
+            );
+        }
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_jaro_winkler_identical_and_empty() {
+        assert_eq!(
+            strings::jaro_winkler("DOE", "DOE"),
+            1.0,
+            "Identical mismatch!"
+        );
+        assert_eq!(
+            strings::jaro_winkler("José", "José"),
+            1.0,
+            "Identical mismatch!"
+        );
+        assert_eq!(strings::jaro_winkler("", ""), 1.0, "Empty mismatch!");
+        assert_eq!(strings::jaro_winkler("DOE", ""), 0.0, "Empty mismatch!");
+        assert!(
+            strings::jaro_winkler("JOHNSON", "JONSON")
+                > strings::jaro_winkler("JOHNSON", "JACKSON"),
+            "Similar names should score higher than dissimilar ones!"
+        );
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_tokenize_sentence() {
+        let input = RUMString::from("Patient (62 y/o) denies fever; reports   cough!");
+        let expected = vec![
+            "Patient", "62", "y", "o", "denies", "fever", "reports", "cough",
+        ];
+        let result = strings::tokenize(&input);
+        println!(
+            "Input: {} Expected: {:?} Got: {:?}",
+            input, expected, result
+        );
+        assert_eq!(result, expected, "Tokens mismatch!");
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_tokenize_with_options() {
+        let input = RUMString::from("Dosage: 500mg, TWICE daily for 10 días.");
+        let lowercased = strings::tokenize_with(&input, true, true);
+        let expected = vec!["dosage", "500mg", "twice", "daily", "for", "10", "días"];
+        println!(
+            "Input: {} Expected: {:?} Got: {:?}",
+            input, expected, lowercased
+        );
+        assert_eq!(lowercased, expected, "Lowercased tokens mismatch!");
+
+        let no_numbers = strings::tokenize_with(&input, false, false);
+        let expected = vec!["Dosage", "500mg", "TWICE", "daily", "for", "días"];
+        println!(
+            "Input: {} Expected: {:?} Got: {:?}",
+            input, expected, no_numbers
+        );
+        assert_eq!(no_numbers, expected, "Tokens without numbers mismatch!");
+
+        let empty = strings::tokenize(" ,;. ");
+        assert!(
+            empty.is_empty(),
+            "Punctuation only input should yield no tokens!"
+        );
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_rumstring_from_utf8_valid() {
+        let input = "MSH|^~\\&|Ünïcödé".as_bytes();
+        let expected = "MSH|^~\\&|Ünïcödé";
+        let lossy = strings::rumstring_from_utf8_lossy(input);
+        let strict = strings::rumstring_from_utf8(input).unwrap();
+        println!(
+            "Input: {:?} Expected: {} Got: {} {}",
+            input, expected, lossy, strict
+        );
+        assert_eq!(lossy, expected, "Lossy decoding mismatch!");
+        assert_eq!(strict, expected, "Strict decoding mismatch!");
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_rumstring_from_utf8_invalid() {
+        let input: &[u8] = b"PID|\xc3\x28|DOE\xff";
+        let expected = "PID|\u{FFFD}(|DOE\u{FFFD}";
+        let lossy = strings::rumstring_from_utf8_lossy(input);
+        println!("Input: {:?} Expected: {} Got: {}", input, expected, lossy);
+        assert_eq!(
+            lossy, expected,
+            "Lossy decoding did not replace invalid sequences!"
+        );
+        let strict = strings::rumstring_from_utf8(input);
+        assert!(strict.is_err(), "Strict decoding should have failed!");
+        println!("Error: {}", strict.unwrap_err());
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_rumstring_from_utf8_empty() {
+        assert_eq!(
+            strings::rumstring_from_utf8_lossy(b""),
+            "",
+            "Lossy empty mismatch!"
+        );
+        assert_eq!(
+            strings::rumstring_from_utf8(b"").unwrap(),
+            "",
+            "Strict empty mismatch!"
+        );
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_content_hash_stability() {
+        let input = RUMString::from("a");
+        let expected: u64 = 0xaf63dc4c8601ec8c;
+        let result = strings::content_hash(&input);
+        println!(
+            "Input: {} Expected: {:x} Got: {:x}",
+            input, expected, result
+        );
+        assert_eq!(result, expected, "Hash is not the published FNV-1a value!");
+        assert_eq!(
+            strings::content_hash("MSH|^~\\&|ADT"),
+            strings::content_hash(&RUMString::from("MSH|^~\\&|ADT")),
+            "Equal strings hashed differently!"
+        );
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_content_hash_different_content() {
+        let a = strings::content_hash("PID|1||12345");
+        let b = strings::content_hash("PID|1||12346");
+        println!("Hashes: {:x} {:x}", a, b);
+        assert_ne!(a, b, "Different content produced the same hash!");
+        println!("Passed!")
+    }
+
+    #[cfg(feature = "blake3")]
+    #[test]
+    fn test_content_digest() {
+        let input = RUMString::from("");
+        let expected = "af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262";
+        let result = strings::hex_encode(&strings::content_digest(&input));
+        println!("Input: {} Expected: {} Got: {}", input, expected, result);
+        assert_eq!(result, expected, "Digest mismatch!");
+        assert_ne!(
+            strings::content_digest("DOE"),
+            strings::content_digest("DOF"),
+            "Different content produced the same digest!"
+        );
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_constant_time_eq() {
+        let token = RUMString::from("hmac-9f86d081884c7d659a2feaa0c55ad015");
+        let cases = [
+            ("hmac-9f86d081884c7d659a2feaa0c55ad015", true),
+            ("hmac-9f86d081884c7d659a2feaa0c55ad016", false),
+            ("Hmac-9f86d081884c7d659a2feaa0c55ad015", false),
+            ("hmac-9f86d081884c7d659a2feaa0c55ad01", false),
+            ("", false),
+        ];
+        for (candidate, expected) in cases {
+            let result = strings::constant_time_eq(&token, candidate);
+            println!(
+                "Input: {} Expected: {} Got: {}",
+                candidate, expected, result
+            );
+            assert_eq!(result, expected, "Comparison mismatch for {}!", candidate);
+        }
+        assert!(
+            strings::constant_time_eq("", ""),
+            "Empty strings should be equal!"
+        );
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_rumstring_from_cstr_ptr_roundtrip() {
+        let input = "PID|1||12345^^^MRN||DOÉ^JOHN";
+        let c_string = std::ffi::CString::new(input).unwrap();
+        let result = unsafe { strings::rumstring_from_cstr_ptr(c_string.as_ptr()) }.unwrap();
+        println!("Input: {} Expected: {} Got: {}", input, input, result);
+        assert_eq!(result, input, "Round trip through CString mismatch!");
+        let back = std::ffi::CString::new(result.as_str()).unwrap();
+        assert_eq!(back, c_string, "Round trip back to CString mismatch!");
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_rumstring_from_cstr_ptr_invalid() {
+        let null_result = unsafe { strings::rumstring_from_cstr_ptr(std::ptr::null()) };
+        assert!(null_result.is_err(), "Null pointer should be an error!");
+        let invalid = std::ffi::CString::new(b"DOE\xff".to_vec()).unwrap();
+        let invalid_result = unsafe { strings::rumstring_from_cstr_ptr(invalid.as_ptr()) };
+        assert!(invalid_result.is_err(), "Invalid UTF-8 should be an error!");
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_rumrope_large_message() {
+        let segment_count = 100_000;
+        let mut rope = RUMRope::new();
+        let mut expected = String::new();
+        for i in 0..segment_count {
+            let segment = format!("OBX|{}|NM|8867-4^Heart rate^LN||{}|/min\r", i, i % 200);
+            rope.push(&segment);
+            expected.push_str(&segment);
+        }
+        let result = rope.collapse();
+        println!(
+            "Segments: {} Expected length: {} Got: {}",
+            segment_count,
+            expected.len(),
+            result.len()
+        );
+        assert_eq!(rope.len(), expected.len(), "Tracked length mismatch!");
+        assert_eq!(rope.chunk_count(), segment_count, "Chunk count mismatch!");
+        assert_eq!(result, expected.as_str(), "Collapsed message mismatch!");
+        assert_eq!(
+            result.capacity(),
+            result.len(),
+            "Collapse should allocate the final buffer exactly once!"
+        );
+        assert_eq!(rope.to_string(), expected, "Display output mismatch!");
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_rumrope_append_outpaces_rebuild() {
+        use std::time::Instant;
+        let segment_count = 5_000;
+        let segments: Vec<String> = (0..segment_count)
+            .map(|i| format!("OBX|{}|NM|8867-4^Heart rate^LN||{}|/min\r", i, i % 200))
+            .collect();
+        let start = Instant::now();
+        let mut rope = RUMRope::new();
+        for segment in segments.iter() {
+            rope.push(segment);
+        }
+        let collapsed = rope.collapse();
+        let rope_time = start.elapsed();
+        let start = Instant::now();
+        let mut rebuilt = RUMString::default();
+        for segment in segments.iter() {
+            rebuilt = format_compact!("{}{}", rebuilt, segment);
+        }
+        let rebuild_time = start.elapsed();
+        println!(
+            "Segments: {} Rope: {:?} Rebuild baseline: {:?}",
+            segment_count, rope_time, rebuild_time
+        );
+        assert_eq!(collapsed, rebuilt, "Rope and baseline disagree!");
+        assert!(
+            rope_time < rebuild_time,
+            "Rope appends should beat rebuilding the string on every append!"
+        );
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_rumrope_empty() {
+        let mut rope = RUMRope::new();
+        assert!(rope.is_empty(), "New rope should be empty!");
+        rope.push("");
+        assert!(rope.is_empty(), "Empty pieces should be ignored!");
+        assert_eq!(rope.chunk_count(), 0, "Empty pieces should not be stored!");
+        assert_eq!(
+            rope.collapse(),
+            "",
+            "Empty rope should collapse to an empty string!"
+        );
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_count_occurrences() {
+        let input = RUMString::from("OBX|1|ST|||aaaa||");
+        let cases = [
+            ("|", 7),
+            ("||", 2),
+            ("aa", 2),
+            ("OBX", 1),
+            ("PID", 0),
+            ("", 0),
+        ];
+        for (needle, expected) in cases {
+            let result = strings::count_occurrences(&input, needle);
+            println!(
+                "Input: {} Needle: {} Expected: {} Got: {}",
+                input, needle, expected, result
+            );
+            assert_eq!(result, expected, "Count mismatch for needle {}!", needle);
+        }
+        assert_eq!(
+            strings::count_occurrences("", "|"),
+            0,
+            "Empty input should have no occurrences!"
+        );
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_to_name_case_all_caps() {
+        let cases = [
+            ("DOE^JOHN", "Doe^John"),
+            ("SMITH-JONES^MARY ANN", "Smith-Jones^Mary Ann"),
+            ("O'BRIEN^PATRICK", "O'Brien^Patrick"),
+            ("MCDONALD^RONALD", "McDonald^Ronald"),
+            ("MÜLLER^JÖRG", "Müller^Jörg"),
+        ];
+        for (input, expected) in cases {
+            let result = strings::to_name_case(input);
+            println!("Input: {} Expected: {} Got: {}", input, expected, result);
+            assert_eq!(result, expected, "Name casing mismatch for {}!", input);
+        }
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_to_name_case_mixed_case() {
+        let cases = [
+            ("mcdonald", "McDonald"),
+            ("McDONALD", "McDonald"),
+            ("jOHN  smith", "John  Smith"),
+            ("d'angelo", "D'Angelo"),
+            ("", ""),
+        ];
+        for (input, expected) in cases {
+            let result = strings::to_name_case(input);
+            println!("Input: {} Expected: {} Got: {}", input, expected, result);
+            assert_eq!(result, expected, "Name casing mismatch for {}!", input);
+        }
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_to_name_case_particles() {
+        let cases = [
+            ("LUDWIG VAN BEETHOVEN", "Ludwig van Beethoven"),
+            ("DE LA CRUZ^MARIA", "De la Cruz^Maria"),
+            ("GARCIA Y LOPEZ^JOSE", "Garcia y Lopez^Jose"),
+            ("VON NEUMANN^JOHN", "Von Neumann^John"),
+            ("DOE^JOHN^^III", "Doe^John^^III"),
+            ("MC", "Mc"),
+        ];
+        for (input, expected) in cases {
+            let result = strings::to_name_case(input);
+            println!("Input: {} Expected: {} Got: {}", input, expected, result);
+            assert_eq!(result, expected, "Name casing mismatch for {}!", input);
+        }
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_rumerror_variants_display() {
+        let message = RUMString::from("Something went wrong!");
+        let errors = [
+            RUMError::io(std::io::ErrorKind::Other, message.clone()),
+            RUMError::parse(message.clone()),
+            RUMError::python(message.clone()),
+            RUMError::cache(message.clone()),
+            RUMError::other(message.clone()),
+        ];
+        for error in errors.iter() {
+            let result = error.to_string();
+            println!("Input: {:?} Expected: {} Got: {}", error, message, result);
+            assert_eq!(result, message, "Display output mismatch for {:?}!", error);
+            assert_eq!(
+                error.message(),
+                &message,
+                "Message mismatch for {:?}!",
+                error
+            );
+        }
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_rumerror_from_rumstring() {
+        let fallible = || -> RUMResult<()> {
+            Err(format_compact!("Legacy string error!"))?;
+            Ok(())
+        };
+        let result = fallible().unwrap_err();
+        println!("Got: {:?}", result);
+        assert_eq!(
+            result,
+            RUMError::other(RUMString::from("Legacy string error!")),
+            "String errors should convert into RUMError::Other!"
+        );
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_rumerror_match_and_downcast() {
+        let parse_error = strings::base64_decode("not base64!").unwrap_err();
+        assert!(
+            matches!(parse_error, RUMError::Parse(..)),
+            "Expected a parse error! Got {:?}!",
+            parse_error
+        );
+        let boxed: Box<dyn std::error::Error> = Box::new(parse_error.clone());
+        let downcasted = boxed.downcast_ref::<RUMError>();
+        println!("Got: {:?}", downcasted);
+        assert_eq!(
+            downcasted,
+            Some(&parse_error),
+            "Boxed error did not downcast back to RUMError!"
+        );
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_with_context_message() {
+        let result = strings::base64_decode("not base64!").with_context(|| "decoding OBX-5");
+        let error = result.unwrap_err();
+        let message = error.to_string();
+        println!("Got: {}", message);
+        assert!(
+            message.starts_with("decoding OBX-5: "),
+            "Context missing from message!"
+        );
+        assert!(
+            message.contains("not valid base64"),
+            "Underlying cause missing from message!"
+        );
+        match error {
+            RUMError::Context {
+                context, source, ..
+            } => {
+                assert_eq!(context, "decoding OBX-5", "Context mismatch!");
+                assert!(
+                    matches!(*source, RUMError::Parse(..)),
+                    "Original error was not preserved!"
+                );
+            }
+            _ => panic!("Expected a context error!"),
+        }
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_with_context_nested_and_ok() {
+        let parse = || -> RUMResult<i64> {
+            strings::parse_number::<i64>("12a").with_context(|| "parsing PID-3")
+        };
+        let error = parse()
+            .with_context(|| format_compact!("processing message {}", 42))
+            .unwrap_err();
+        let message = error.to_string();
+        println!("Got: {}", message);
+        assert!(
+            message.starts_with("processing message 42: parsing PID-3: "),
+            "Nested context mismatch!"
+        );
+        let ok: RUMResult<i64> = Ok(5);
+        let result = ok.with_context(|| -> RUMString { panic!("Context should be lazy!") });
+        assert_eq!(result, Ok(5), "Ok values should pass through untouched!");
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_or_log() {
+        let ok: RUMResult<i64> = Ok(7);
+        assert_eq!(ok.or_log(), 7, "Ok value should be returned!");
+        let err: RUMResult<i64> = Err(RUMError::parse(RUMString::from("Bad number!")));
+        assert_eq!(err.or_log(), 0, "Default should be returned on error!");
+        let err: RUMResult<RUMString> = Err(RUMError::other(RUMString::from("Missing!")));
+        assert_eq!(err.or_log(), "", "Default should be returned on error!");
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_tap_err() {
+        let mut seen: Vec<RUMString> = Vec::new();
+        let ok: RUMResult<i64> = Ok(7);
+        let result = ok.tap_err(|e| seen.push(e.to_string().into()));
+        assert_eq!(result, Ok(7), "Ok value should pass through!");
+        assert!(seen.is_empty(), "Callback should not run on Ok!");
+
+        let err: RUMResult<i64> = Err(RUMError::cache(RUMString::from("Cache miss!")));
+        let result = err.tap_err(|e| seen.push(e.to_string().into()));
+        assert_eq!(
+            result,
+            Err(RUMError::cache(RUMString::from("Cache miss!"))),
+            "Error should pass through untouched!"
+        );
+        assert_eq!(
+            seen,
+            vec!["Cache miss!"],
+            "Callback should run once on Err!"
+        );
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_map_err_msg() {
+        let ok: RUMResult<i64> = Ok(7);
+        assert_eq!(
+            ok.map_err_msg("Unused!"),
+            Ok(7),
+            "Ok value should pass through!"
+        );
+
+        let err = strings::parse_number::<i64>("abc").map_err_msg("PID-3 must be numeric!");
+        println!("Got: {:?}", err);
+        assert_eq!(
+            err,
+            Err(RUMError::parse(RUMString::from("PID-3 must be numeric!"))),
+            "Message should be replaced and category kept!"
+        );
+
+        let err = strings::parse_number::<i64>("abc")
+            .with_context(|| "parsing PID")
+            .map_err_msg("parsing PID-3");
+        let message = err.unwrap_err().to_string();
+        println!("Got: {}", message);
+        assert!(
+            message.starts_with("parsing PID-3: "),
+            "Context should be replaced and cause kept!"
+        );
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_rumerror_from_io_error() {
+        let read_missing = || -> RUMResult<String> {
+            let contents = std::fs::read_to_string("/tmp/rumtk-this-file-does-not-exist.txt")?;
+            Ok(contents)
+        };
+        let error = read_missing().unwrap_err();
+        println!("Got: {:?}", error);
+        assert!(
+            matches!(error, RUMError::Io(std::io::ErrorKind::NotFound, _, _)),
+            "Expected a NotFound IO error! Got {:?}!",
+            error
+        );
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_py_load_missing_file() {
+        use crate::scripting::python_utils::py_load;
+        use pyo3::Python;
+        let fpath = "/tmp/rumtk-this-module-does-not-exist.py";
+        let error = Python::attach(|py| py_load(py, fpath).map(|_| ())).unwrap_err();
+        println!("Got: {:?}", error);
+        assert!(
+            matches!(
+                &error,
+                RUMError::Context { source, .. }
+                    if matches!(**source, RUMError::Io(std::io::ErrorKind::NotFound, _, _))
+            ),
+            "Expected a NotFound IO error! Got {:?}!",
+            error
+        );
+        assert!(
+            error.to_string().contains(fpath),
+            "Error does not name the module file! Got {}!",
+            error
+        );
+        println!("Passed!")
+    }
+
+    #[cfg(feature = "backtrace")]
+    #[test]
+    fn test_rumerror_backtrace() {
+        // Backtrace::capture caches the environment on first use, so run the checks in a fresh
+        // process with RUST_BACKTRACE set instead of mutating this process's environment.
+        let output = std::process::Command::new(std::env::current_exe().unwrap())
+            .args([
+                "--exact",
+                "tests::test_rumerror_backtrace_captured",
+                "--ignored",
+                "--nocapture",
+            ])
+            .env("RUST_BACKTRACE", "1")
+            .env_remove("RUST_LIB_BACKTRACE")
+            .output()
+            .unwrap();
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        println!("Got: {}", stdout);
+        assert!(output.status.success(), "Backtrace checks failed!");
+        assert!(stdout.contains("1 passed"), "Backtrace checks did not run!");
+        println!("Passed!")
+    }
+
+    #[cfg(feature = "backtrace")]
+    #[test]
+    #[ignore = "run by test_rumerror_backtrace with RUST_BACKTRACE=1"]
+    fn test_rumerror_backtrace_captured() {
+        use std::backtrace::BacktraceStatus;
+        let errors = vec![
+            RUMError::other("No context!"),
+            RUMError::from(std::io::Error::from(std::io::ErrorKind::NotFound)),
+            strings::parse_number::<i64>("abc").unwrap_err(),
+            strings::parse_number::<i64>("abc")
+                .with_context(|| "parsing PID-3")
+                .unwrap_err(),
+        ];
+        for error in errors {
+            let status = error.backtrace().map(|trace| trace.status());
+            println!("Input: {:?} Got: {:?}", error, status);
+            assert_eq!(
+                status,
+                Some(BacktraceStatus::Captured),
+                "Expected a captured backtrace!"
+            );
+        }
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_rumerror_codes() {
+        use std::io::ErrorKind;
+        let message = RUMString::from("Something went wrong!");
+        let cases = [
+            (RUMError::io(ErrorKind::Other, message.clone()), 1000),
+            (RUMError::io(ErrorKind::NotFound, message.clone()), 1001),
+            (
+                RUMError::io(ErrorKind::PermissionDenied, message.clone()),
+                1002,
+            ),
+            (
+                RUMError::io(ErrorKind::ConnectionRefused, message.clone()),
+                1003,
+            ),
+            (
+                RUMError::io(ErrorKind::ConnectionReset, message.clone()),
+                1004,
+            ),
+            (
+                RUMError::io(ErrorKind::ConnectionAborted, message.clone()),
+                1005,
+            ),
+            (RUMError::io(ErrorKind::NotConnected, message.clone()), 1006),
+            (RUMError::io(ErrorKind::AddrInUse, message.clone()), 1007),
+            (
+                RUMError::io(ErrorKind::AddrNotAvailable, message.clone()),
+                1008,
+            ),
+            (RUMError::io(ErrorKind::BrokenPipe, message.clone()), 1009),
+            (
+                RUMError::io(ErrorKind::AlreadyExists, message.clone()),
+                1010,
+            ),
+            (RUMError::io(ErrorKind::WouldBlock, message.clone()), 1011),
+            (RUMError::io(ErrorKind::InvalidInput, message.clone()), 1012),
+            (RUMError::io(ErrorKind::InvalidData, message.clone()), 1013),
+            (RUMError::io(ErrorKind::TimedOut, message.clone()), 1014),
+            (RUMError::io(ErrorKind::WriteZero, message.clone()), 1015),
+            (RUMError::io(ErrorKind::Interrupted, message.clone()), 1016),
+            (RUMError::io(ErrorKind::Unsupported, message.clone()), 1017),
+            (
+                RUMError::io(ErrorKind::UnexpectedEof, message.clone()),
+                1018,
+            ),
+            (RUMError::io(ErrorKind::OutOfMemory, message.clone()), 1019),
+            (RUMError::parse(message.clone()), 2000),
+            (RUMError::python(message.clone()), 3000),
+            (RUMError::cache(message.clone()), 4000),
+            (RUMError::other(message.clone()), 9000),
+        ];
+        let mut seen = ahash::AHashSet::new();
+        for (error, expected) in cases.iter() {
+            let result = error.code();
+            println!("Input: {:?} Expected: {} Got: {}", error, expected, result);
+            assert_eq!(result, *expected, "Code mismatch for {:?}!", error);
+            assert!(seen.insert(result), "Code {} is not unique!", result);
+        }
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_rumerror_code_through_context() {
+        let error = strings::parse_number::<i64>("abc")
+            .with_context(|| "parsing PID-3")
+            .unwrap_err();
+        println!("Got: {}", error.code());
+        assert_eq!(
+            error.code(),
+            2000,
+            "Context should report the wrapped code!"
+        );
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_rumerror_from_pyerr() {
+        use crate::scripting::python_utils::{py_exec_module, py_load, py_new_args};
+        use pyo3::Python;
+        let module = "def test():\n\traise ValueError('MRN 12345 is not valid')\n";
+        let fpath = format_compact!("/tmp/{}.py", uuid::Uuid::new_v4());
+        std::fs::write(&fpath, module.as_bytes()).expect("Failure to write test module.");
+        let error = Python::attach(|py| {
+            let pymod = py_load(py, &fpath)?;
+            py_exec_module(py, &pymod, "test", &py_new_args(py))
+        })
+        .unwrap_err();
+        std::fs::remove_file(&fpath).unwrap();
+        let message = error.to_string();
+        println!("Got: {}", message);
+        assert!(
+            message
+                .starts_with("executing Python function test: ValueError: MRN 12345 is not valid"),
+            "Function name or exception missing from message!"
+        );
+        assert!(
+            message.contains("Traceback"),
+            "Traceback missing from message!"
+        );
+        assert!(
+            message.contains("line 2, in test"),
+            "Traceback line and function missing from message!"
+        );
+        let RUMError::Context { source, .. } = error else {
+            panic!("Expected a context error! Got {:?}!", error);
+        };
+        assert!(
+            matches!(*source, RUMError::Python(..)),
+            "Expected a Python error! Got {:?}!",
+            source
+        );
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_py_load_cached_skips_recompile() {
+        use crate::scripting::python_utils::py_load_cached;
+        use pyo3::types::PyAnyMethods;
+        use pyo3::Python;
+        let counter = format!("rumtk_loads_{}", uuid::Uuid::new_v4().simple());
+        let module = format!(
+            "import builtins\nbuiltins.{0} = getattr(builtins, '{0}', 0) + 1\n",
+            counter
+        );
+        let fpath = format_compact!("/tmp/{}.py", uuid::Uuid::new_v4());
+        std::fs::write(&fpath, module.as_bytes()).expect("Failure to write test module.");
+        let (loads, first_elapsed, second_elapsed, same) = Python::attach(|py| {
+            let start = std::time::Instant::now();
+            let first = py_load_cached(py, &fpath, true).unwrap();
+            let first_elapsed = start.elapsed();
+            let start = std::time::Instant::now();
+            let second = py_load_cached(py, &fpath, true).unwrap();
+            let second_elapsed = start.elapsed();
+            let loads: usize = py
+                .import("builtins")
+                .unwrap()
+                .getattr(counter.as_str())
+                .unwrap()
+                .extract()
+                .unwrap();
+            (loads, first_elapsed, second_elapsed, first.is(&second))
+        });
+        std::fs::remove_file(&fpath).unwrap();
+        println!(
+            "First load: {:?} Cached load: {:?}",
+            first_elapsed, second_elapsed
+        );
+        println!("Expected: 1 Got: {}", loads);
+        assert_eq!(loads, 1, "Module was compiled more than once!");
+        assert!(same, "Cached load returned a different module!");
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_py_load_cached_reloads_on_mtime_change() {
+        use crate::scripting::python_utils::{py_exec_module, py_load_cached, py_new_args};
+        use pyo3::Python;
+        use std::time::{Duration, SystemTime};
+        let fpath = format_compact!("/tmp/{}.py", uuid::Uuid::new_v4());
+        let touch = |contents: &str, offset: u64| {
+            std::fs::write(&fpath, contents.as_bytes()).expect("Failure to write test module.");
+            let file = std::fs::File::options().write(true).open(&fpath).unwrap();
+            file.set_modified(SystemTime::UNIX_EPOCH + Duration::from_secs(offset))
+                .unwrap();
+        };
+        let call = |check_mtime: bool| -> RUMString {
+            Python::attach(|py| {
+                let pymod = py_load_cached(py, &fpath, check_mtime).unwrap();
+                let result = py_exec_module(py, &pymod, "test", &py_new_args(py)).unwrap();
+                result.extract::<String>(py).unwrap().into()
+            })
+        };
+        touch("def test():\n\treturn 'v1'\n", 1000);
+        let first = call(true);
+        touch("def test():\n\treturn 'v2'\n", 2000);
+        let unchecked = call(false);
+        let checked = call(true);
+        std::fs::remove_file(&fpath).unwrap();
+        println!(
+            "Expected: (v1, v1, v2) Got: ({}, {}, {})",
+            first, unchecked, checked
+        );
+        assert_eq!(first, "v1", "Initial load returned the wrong module!");
+        assert_eq!(unchecked, "v1", "Opting out of the mtime check reloaded!");
+        assert_eq!(checked, "v2", "Changed module was not reloaded!");
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_py_load_cached_missing_file() {
+        use crate::scripting::python_utils::py_load_cached;
+        use pyo3::Python;
+        let fpath = format_compact!("/tmp/{}.py", uuid::Uuid::new_v4());
+        let error = Python::attach(|py| py_load_cached(py, &fpath, true)).unwrap_err();
+        println!("Got: {}", error);
+        assert!(
+            error.to_string().contains(fpath.as_str()),
+            "Module path missing from error!"
+        );
+        assert!(
+            matches!(
+                &error,
+                RUMError::Context { source, .. }
+                    if matches!(**source, RUMError::Io(std::io::ErrorKind::NotFound, _, _))
+            ),
+            "Expected a NotFound cause! Got {:?}!",
+            error
+        );
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_py_load_cached_normalizes_key() {
+        use crate::scripting::python_utils::py_load_cached;
+        use pyo3::Python;
+        let name = format_compact!("{}.py", uuid::Uuid::new_v4());
+        let fpath = format_compact!("/tmp/{}", name);
+        let dotted = format_compact!("/tmp/./{}", name);
+        std::fs::write(&fpath, b"def test():\n\treturn 'Hello'")
+            .expect("Failure to write test module.");
+        let same = Python::attach(|py| {
+            let first = py_load_cached(py, &fpath, true).unwrap();
+            let second = py_load_cached(py, &dotted, true).unwrap();
+            first.is(&second)
+        });
+        std::fs::remove_file(&fpath).unwrap();
+        println!(
+            "Input: {:?} Got: {}",
+            (fpath.as_str(), dotted.as_str()),
+            same
+        );
+        assert!(same, "Equivalent paths compiled the module twice!");
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_py_exec_module_kwargs_empty() {
+        use crate::scripting::python_utils::{
+            py_exec_module, py_exec_module_kwargs, py_load, py_new_args, py_push_arg, RUMPyKwargs,
+        };
+        use pyo3::Python;
+        let fpath = format_compact!("/tmp/{}.py", uuid::Uuid::new_v4());
+        std::fs::write(&fpath, b"def test(a, *, scale=1.0):\n\treturn a*scale\n")
+            .expect("Failure to write test module.");
+        let (expected, result) = Python::attach(|py| -> RUMResult<(f64, f64)> {
+            let pymod = py_load(py, &fpath)?;
+            let mut args = py_new_args(py);
+            py_push_arg(py, &mut args, &3.0)?;
+            let positional = py_exec_module(py, &pymod, "test", &args)?;
+            let keyword = py_exec_module_kwargs(py, &pymod, "test", &args, &RUMPyKwargs::new())?;
+            Ok((positional.extract(py)?, keyword.extract(py)?))
+        })
+        .unwrap();
+        std::fs::remove_file(&fpath).unwrap();
+        println!("Expected: {} Got: {}", expected, result);
+        assert_eq!(expected, result, "Empty kwargs did not behave like call1!");
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_py_exec_module_kwargs_unknown_key() {
+        use crate::scripting::python_utils::{
+            py_exec_module_kwargs, py_load, py_new_args, py_push_arg, py_push_kwarg, RUMPyKwargs,
+        };
+        use pyo3::Python;
+        let fpath = format_compact!("/tmp/{}.py", uuid::Uuid::new_v4());
+        std::fs::write(&fpath, b"def test(a, *, scale=1.0):\n\treturn a*scale\n")
+            .expect("Failure to write test module.");
+        let error = Python::attach(|py| {
+            let pymod = py_load(py, &fpath)?;
+            let mut args = py_new_args(py);
+            py_push_arg(py, &mut args, &3.0)?;
+            let mut kwargs = RUMPyKwargs::new();
+            py_push_kwarg(py, &mut kwargs, "offset", &1.0)?;
+            py_exec_module_kwargs(py, &pymod, "test", &args, &kwargs)
+        })
+        .unwrap_err();
+        std::fs::remove_file(&fpath).unwrap();
+        let message = error.to_string();
+        println!("Got: {}", message);
+        assert!(
+            message.contains("offset"),
+            "Error does not name the offending keyword! Got {}!",
+            message
+        );
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_py_exec_module_nested_traceback() {
+        use crate::scripting::python_utils::{py_exec_module, py_load, py_new_args};
+        use pyo3::Python;
+        let module = "def validate_pid(segment):\n\
+                      \tif not segment:\n\
+                      \t\traise KeyError('PID-3')\n\
+                      \treturn segment\n\
+                      \n\
+                      def transform(segment):\n\
+                      \treturn validate_pid(segment)\n\
+                      \n\
+                      def test():\n\
+                      \treturn transform('')\n";
+        let fpath = format_compact!("/tmp/{}.py", uuid::Uuid::new_v4());
+        std::fs::write(&fpath, module.as_bytes()).expect("Failure to write test module.");
+        let error = Python::attach(|py| {
+            let pymod = py_load(py, &fpath)?;
+            py_exec_module(py, &pymod, "test", &py_new_args(py))
+        })
+        .unwrap_err();
+        std::fs::remove_file(&fpath).unwrap();
+        let message = error.to_string();
+        println!("Got: {}", message);
+        assert!(
+            message.starts_with("executing Python function test: KeyError: 'PID-3'"),
+            "Exception type and value missing from message!"
+        );
+        assert!(
+            message.contains("line 3, in validate_pid"),
+            "Inner function name and line missing from traceback!"
+        );
+        assert!(
+            message.contains("in transform"),
+            "Intermediate frame missing from traceback!"
+        );
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_py_extract_string_map_non_string_entry() {
+        use crate::scripting::python_utils::{
+            py_exec_module, py_extract_string_map, py_load, py_new_args,
+        };
+        use pyo3::Python;
+        let fpath = format_compact!("/tmp/{}.py", uuid::Uuid::new_v4());
+        std::fs::write(
+            &fpath,
+            b"def values():\n\treturn {'patient': 'Doe', 'mrn': 123}\n\ndef keys():\n\treturn {7: 'Doe'}\n",
+        )
+        .expect("Failure to write test module.");
+        let (value_error, key_error) = Python::attach(|py| {
+            let pymod = py_load(py, &fpath).unwrap();
+            let values = py_exec_module(py, &pymod, "values", &py_new_args(py)).unwrap();
+            let keys = py_exec_module(py, &pymod, "keys", &py_new_args(py)).unwrap();
+            (
+                py_extract_string_map(py, &values).unwrap_err(),
+                py_extract_string_map(py, &keys).unwrap_err(),
+            )
+        });
+        std::fs::remove_file(&fpath).unwrap();
+        println!("Got: {} | {}", value_error, key_error);
+        assert!(
+            value_error.message().contains("123") && value_error.message().contains("mrn"),
+            "Error does not name the offending value! Got {}!",
+            value_error
+        );
+        assert!(
+            key_error.message().contains("key 7"),
+            "Error does not name the offending key! Got {}!",
+            key_error
+        );
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_py_interpreter_alternating_modules() {
+        use crate::scripting::python_utils::{py_buildargs, RUMPyInterpreter};
+        use pyo3::Python;
+        let upper = format_compact!("/tmp/{}.py", uuid::Uuid::new_v4());
+        let lower = format_compact!("/tmp/{}.py", uuid::Uuid::new_v4());
+        std::fs::write(&upper, b"def test(s):\n\treturn s.upper()\n")
+            .expect("Failure to write test module.");
+        std::fs::write(&lower, b"def test(s):\n\treturn s.lower()\n")
+            .expect("Failure to write test module.");
+        let mut interpreter = RUMPyInterpreter::new();
+        interpreter.load_module("upper", &upper).unwrap();
+        interpreter.load_module("lower", &lower).unwrap();
+        std::fs::remove_file(&upper).unwrap();
+        std::fs::remove_file(&lower).unwrap();
+
+        let args = Python::attach(|py| py_buildargs(py, &vec!["Doe^John"]).unwrap());
+        let mut results: Vec<String> = Vec::new();
+        for module in ["upper", "lower", "upper", "lower"] {
+            results.push(interpreter.call_extract(module, "test", &args).unwrap());
+        }
+        let expected = vec!["DOE^JOHN", "doe^john", "DOE^JOHN", "doe^john"];
+        println!("Expected: {:?} Got: {:?}", expected, results);
+        assert_eq!(results, expected, "Calls were routed to the wrong module!");
+
+        let error = interpreter.call("missing", "test", &args).unwrap_err();
+        println!("Got: {}", error);
+        assert!(
+            error.message().contains("missing"),
+            "Error does not name the unregistered module! Got {}!",
+            error
+        );
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_py_exec_module_missing_function() {
+        use crate::scripting::python_utils::{py_exec_module, py_load, py_new_args};
+        use pyo3::Python;
+        let fpath = format_compact!("/tmp/{}.py", uuid::Uuid::new_v4());
+        std::fs::write(&fpath, b"x = 1\n").expect("Failure to write test module.");
+        let error = Python::attach(|py| {
+            let pymod = py_load(py, &fpath)?;
+            py_exec_module(py, &pymod, "missing", &py_new_args(py))
+        })
+        .unwrap_err();
+        std::fs::remove_file(&fpath).unwrap();
+        let message = error.to_string();
+        println!("Got: {}", message);
+        assert!(
+            message.starts_with("executing Python function missing: AttributeError: "),
+            "Expected an AttributeError naming the function! Got {}!",
+            message
+        );
+        println!("Passed!")
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_rumerror_serialize() {
+        let error = RUMError::io(
+            std::io::ErrorKind::NotFound,
+            RUMString::from("Missing config!"),
+        );
+        let expected = r#"{"code":1001,"kind":"Io","message":"Missing config!","context":[]}"#;
+        let result = to_string(&error).unwrap();
+        println!("Input: {:?} Expected: {} Got: {}", error, expected, result);
+        assert_eq!(result, expected, "JSON shape mismatch!");
+        println!("Passed!")
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_rumerror_serialize_with_context() {
+        let error = strings::base64_decode("!")
+            .map_err_msg("Invalid OBX-5 payload!")
+            .with_context(|| "parsing OBX")
+            .with_context(|| "processing ORU^R01")
+            .unwrap_err();
+        let expected = r#"{"code":2000,"kind":"Parse","message":"Invalid OBX-5 payload!","context":["processing ORU^R01","parsing OBX"]}"#;
+        let result = to_string(&error).unwrap();
+        println!("Input: {:?} Expected: {} Got: {}", error, expected, result);
+        assert_eq!(result, expected, "JSON shape mismatch!");
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_collect_results_all_ok() {
+        use crate::core::{collect_all_errors, collect_results};
+        let fields = ["10", "20", "30"];
+        let expected = vec![10, 20, 30];
+        let result = collect_results(fields.iter().map(|f| strings::parse_number::<i32>(f)));
+        println!(
+            "Input: {:?} Expected: {:?} Got: {:?}",
+            fields, expected, result
+        );
+        assert_eq!(
+            result,
+            Ok(expected.clone()),
+            "Fail-fast collection mismatch!"
+        );
+        let result = collect_all_errors(fields.iter().map(|f| strings::parse_number::<i32>(f)));
+        assert_eq!(result, Ok(expected), "Aggregating collection mismatch!");
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_collect_results_fail_fast() {
+        use crate::core::collect_results;
+        let fields = ["10", "x", "30", "y"];
+        let mut evaluated = 0;
+        let result = collect_results(fields.iter().map(|f| {
+            evaluated += 1;
+            strings::parse_number::<i32>(f)
+        }));
+        println!("Input: {:?} Got: {:?}", fields, result);
+        let error = result.unwrap_err();
+        assert!(
+            error.message().contains("x"),
+            "Expected the first error! Got {}!",
+            error
+        );
+        assert_eq!(evaluated, 2, "Collection should stop at the first error!");
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_collect_all_errors_aggregates() {
+        use crate::core::collect_all_errors;
+        let fields = ["10", "x", "30", "y"];
+        let result = collect_all_errors(fields.iter().map(|f| strings::parse_number::<i32>(f)));
+        println!("Input: {:?} Got: {:?}", fields, result);
+        let errors = result.unwrap_err();
+        assert_eq!(errors.len(), 2, "Every error should be reported!");
+        assert!(errors[0].message().contains("x"), "First error mismatch!");
+        assert!(errors[1].message().contains("y"), "Second error mismatch!");
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_instrument_sleep() {
+        use crate::profiling::{instrument, timing_report};
+        use std::time::Duration;
+        let label = "test_instrument_sleep";
+        let nap = Duration::from_millis(20);
+        let result = instrument(label, || {
+            std::thread::sleep(nap);
+            "done"
+        });
+        assert_eq!(result, "done", "Closure result should be returned!");
+        instrument(label, || std::thread::sleep(nap));
+        let report = timing_report();
+        let stats = report.iter().find(|s| s.label == label).unwrap();
+        println!("Got: {:?}", stats);
+        assert_eq!(stats.count, 2, "Both runs should be recorded!");
+        assert!(
+            stats.min >= nap,
+            "Recorded duration is shorter than the sleep!"
+        );
+        assert!(
+            stats.max < Duration::from_secs(2),
+            "Recorded duration is implausibly long!"
+        );
+        assert!(
+            stats.total >= nap * 2 && stats.mean() >= nap,
+            "Totals do not add up!"
+        );
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_catch_rum_panic() {
+        use crate::core::catch_rum;
+        let result = catch_rum(|| -> usize {
+            let segments: Vec<&str> = Vec::new();
+            segments[3].len()
+        });
+        println!("Got: {:?}", result);
+        let error = result.unwrap_err();
+        assert_eq!(error.code(), 9000, "Panics should become RUMError::Other!");
+        assert!(
+            error.message().contains("index out of bounds"),
+            "Panic message missing! Got {}!",
+            error
+        );
+
+        let result: RUMResult<()> = catch_rum(|| panic!("Bad message {}!", 42));
+        assert!(
+            result.unwrap_err().message().contains("Bad message 42!"),
+            "Formatted panic message missing!"
+        );
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_catch_rum_ok() {
+        use crate::core::catch_rum;
+        let result = catch_rum(|| strings::parse_number::<i32>("7"));
+        assert_eq!(result, Ok(Ok(7)), "Closure result should be returned!");
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_ok_or_msg_some() {
+        use crate::core::OptionExt;
+        let value = Some(RUMString::from("DOE^JOHN"));
+        let result = value.ok_or_msg("PID-5 is missing!");
+        println!("Got: {:?}", result);
+        assert_eq!(
+            result,
+            Ok(RUMString::from("DOE^JOHN")),
+            "Some should become Ok!"
+        );
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_ok_or_msg_none() {
+        use crate::core::OptionExt;
+        let value: Option<RUMString> = None;
+        let result = value.ok_or_msg(format_compact!("PID-{} is missing!", 5));
+        println!("Got: {:?}", result);
+        assert_eq!(
+            result,
+            Err(RUMError::other(RUMString::from("PID-5 is missing!"))),
+            "None should become an error with the message!"
+        );
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_rumerror_source_chain() {
+        use std::error::Error;
+        let wrapped = || -> Result<i64, Box<dyn Error>> {
+            let value = strings::parse_number::<i64>("abc")
+                .with_context(|| "parsing PID-3")
+                .with_context(|| "parsing PID")?;
+            Ok(value)
+        };
+        let error = wrapped().unwrap_err();
+        let mut chain: Vec<String> = vec![error.to_string()];
+        let mut current = error.source();
+        while let Some(cause) = current {
+            chain.push(cause.to_string());
+            current = cause.source();
+        }
+        println!("Got: {:#?}", chain);
+        assert_eq!(chain.len(), 3, "Expected two contexts and one cause!");
+        assert_eq!(
+            chain[0],
+            format!("parsing PID: parsing PID-3: {}", chain[2]),
+            "Outer context should print the whole chain!"
+        );
+        assert!(
+            chain[1].starts_with("parsing PID-3: "),
+            "Inner context missing!"
+        );
+        let root = error
+            .source()
+            .and_then(|e| e.source())
+            .and_then(|e| e.downcast_ref::<RUMError>())
+            .unwrap();
+        assert!(
+            matches!(root, RUMError::Parse(..)),
+            "Underlying cause is not reachable! Got {:?}!",
+            root
+        );
+        assert!(root.source().is_none(), "Root cause should end the chain!");
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_config_cache_capacity() {
+        use crate::cache::{new_cache, LazyRUMCache};
+        use crate::config::{config, update_config};
+        let capacity = 1000;
+        let previous = config().cache_capacity;
+        update_config(|c| c.cache_capacity = capacity);
+        assert_eq!(config().cache_capacity, capacity, "Config was not updated!");
+        let cache: LazyRUMCache<RUMString, RUMString> = new_cache();
+        let allocated = cache.lock().unwrap().capacity();
+        update_config(|c| c.cache_capacity = previous);
+        println!("Expected: >= {} Got: {}", capacity, allocated);
+        assert!(
+            allocated >= capacity,
+            "Cache did not honor the configured capacity!"
+        );
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_config_redaction_policy() {
+        use crate::config::{config, update_config};
+        use crate::strings::redact_default;
+        let mrn = "123-45-6789";
+        let previous = config().redaction_policy;
+        assert_eq!(
+            previous,
+            RedactionPolicy::Full,
+            "Default policy should be Full!"
+        );
+        update_config(|c| c.redaction_policy = RedactionPolicy::KeepLast(4));
+        let result = redact_default(mrn);
+        update_config(|c| c.redaction_policy = previous);
+        let expected = strings::redact(mrn, RedactionPolicy::KeepLast(4));
+        println!("Input: {} Expected: {} Got: {}", mrn, expected, result);
+        assert_eq!(
+            result, expected,
+            "Configured redaction policy was not used!"
+        );
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_config_python_search_paths() {
+        use crate::config::update_config;
+        use crate::scripting::python_utils::{py_exec_module, py_load, py_new_args};
+        use pyo3::Python;
+        let id = uuid::Uuid::new_v4().simple().to_string();
+        let dir = format_compact!("/tmp/rumtk_config_{}", id);
+        let helper = format_compact!("rumtk_helper_{}", id);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(format!("{}/{}.py", dir, helper), b"VALUE = 'from helper'\n").unwrap();
+        let fpath = format_compact!("/tmp/rumtk_main_{}.py", id);
+        let module = format!(
+            "import {}\ndef test():\n\treturn {}.VALUE\n",
+            helper, helper
+        );
+        std::fs::write(&fpath, module.as_bytes()).unwrap();
+
+        update_config(|c| c.python_search_paths.push(dir.clone()));
+        let result = Python::attach(|py| -> RUMResult<RUMString> {
+            let pymod = py_load(py, &fpath)?;
+            let value = py_exec_module(py, &pymod, "test", &py_new_args(py))?;
+            Ok(value.extract::<String>(py)?.into())
+        });
+        update_config(|c| c.python_search_paths.retain(|path| *path != dir));
+        std::fs::remove_dir_all(&dir).unwrap();
+        std::fs::remove_file(&fpath).unwrap();
+        println!("Got: {:?}", result);
+        assert_eq!(
+            result,
+            Ok(RUMString::from("from helper")),
+            "Module could not import from the configured search path!"
+        );
+        println!("Passed!")
+    }
+
+    #[cfg(feature = "logging")]
+    #[test]
+    fn test_logging_records() {
+        use crate::cache::{get_or_set_from_cache, new_cache, LazyRUMCache};
+        use crate::log::LOG_TARGET;
+        use std::sync::Mutex;
+        use std::thread::ThreadId;
+
+        static RECORDS: Mutex<Vec<(ThreadId, String, String)>> = Mutex::new(Vec::new());
+        struct CaptureLogger;
+        impl ::log::Log for CaptureLogger {
+            fn enabled(&self, _metadata: &::log::Metadata) -> bool {
+                true
+            }
+            fn log(&self, record: &::log::Record) {
+                RECORDS.lock().unwrap().push((
+                    std::thread::current().id(),
+                    record.target().to_string(),
+                    record.args().to_string(),
+                ));
+            }
+            fn flush(&self) {}
+        }
+        static LOGGER: CaptureLogger = CaptureLogger;
+        ::log::set_logger(&LOGGER).unwrap();
+        ::log::set_max_level(::log::LevelFilter::Trace);
+
+        let cache: LazyRUMCache<RUMString, RUMString> = new_cache();
+        let key = RUMString::from("MSH");
+        get_or_set_from_cache(&cache, &key, |k| k.clone());
+        get_or_set_from_cache(&cache, &key, |k| k.clone());
+        let failed: RUMResult<i64> = Err(RUMError::parse(RUMString::from("Bad number!")));
+        failed.or_log();
+        let recovered = RUMError::from(pyo3::exceptions::PyValueError::new_err("recovered"));
+        assert!(
+            matches!(recovered, RUMError::Python(..)),
+            "Wrong variant for Python error!"
+        );
+
+        let this_thread = std::thread::current().id();
+        let records: Vec<(ThreadId, String, String)> = RECORDS
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(thread, _, _)| *thread == this_thread)
+            .cloned()
+            .collect();
+        println!("Got: {:?}", records);
+        assert_eq!(
+            records.len(),
+            2,
+            "Expected one cache miss and one or_log record, and no record for the recovered Python error!"
+        );
+        assert_eq!(records[0].1, LOG_TARGET, "Record target mismatch!");
+        assert!(
+            records[0].2.contains("Cache miss"),
+            "Record message mismatch!"
+        );
+        assert_eq!(records[1].1, LOG_TARGET, "Record target mismatch!");
+        assert_eq!(records[1].2, "Bad number!", "or_log record mismatch!");
+        println!("Passed!")
+    }
+
+    #[cfg(not(feature = "logging"))]
+    #[test]
+    fn test_logging_disabled_is_noop() {
+        use crate::log::{init_logging, LogLevel};
+        assert_eq!(init_logging(LogLevel::Debug), Ok(()), "Should be a no-op!");
+        assert_eq!(init_logging(LogLevel::Trace), Ok(()), "Should be a no-op!");
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_version_info() {
+        use crate::info::version_info;
+        let info = version_info();
+        println!("Got: {:?}", info);
+        assert_eq!(
+            info.version,
+            env!("CARGO_PKG_VERSION"),
+            "Version does not match the crate version!"
+        );
+        assert!(!info.git_hash.is_empty(), "Git hash should never be empty!");
+        assert_eq!(
+            info.features.iter().any(|f| f == "serde"),
+            cfg!(feature = "serde"),
+            "Feature list does not match the build!"
+        );
+        assert_eq!(
+            info.features.iter().any(|f| f == "logging"),
+            cfg!(feature = "logging"),
+            "Feature list does not match the build!"
+        );
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_capabilities_features() {
+        use crate::info::capabilities;
+        let caps = capabilities();
+        println!("Got: {:?}", caps);
+        assert_eq!(
+            caps.serde_enabled,
+            cfg!(feature = "serde"),
+            "serde mismatch!"
+        );
+        assert_eq!(
+            caps.logging_enabled,
+            cfg!(feature = "logging"),
+            "logging mismatch!"
+        );
+        assert_eq!(
+            caps.backtrace_enabled,
+            cfg!(feature = "backtrace"),
+            "backtrace mismatch!"
+        );
+        assert_eq!(
+            caps.collation_enabled,
+            cfg!(feature = "collation"),
+            "collation mismatch!"
+        );
+        assert_eq!(
+            caps.unicode_normalization_enabled,
+            cfg!(feature = "unicode-normalization"),
+            "unicode-normalization mismatch!"
+        );
+        assert_eq!(
+            caps.blake3_enabled,
+            cfg!(feature = "blake3"),
+            "blake3 mismatch!"
+        );
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_capabilities_python() {
+        use crate::info::capabilities;
+        use pyo3::Python;
+        let caps = capabilities();
+        let numpy_importable = Python::attach(|py| py.import("numpy").is_ok());
+        println!("Got: {:?}", caps);
+        assert!(
+            caps.python_available,
+            "The test interpreter should be available!"
+        );
+        assert_eq!(
+            caps.numpy_available, numpy_importable,
+            "numpy detection does not match the interpreter!"
+        );
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_shutdown_hooks_lifo_once() {
+        use crate::shutdown::{register_shutdown_hook, run_shutdown_hooks};
+        use std::sync::{Arc, Mutex};
+        let order = Arc::new(Mutex::new(Vec::<u32>::new()));
+        for i in 1..=3 {
+            let order = order.clone();
+            register_shutdown_hook(move || order.lock().unwrap().push(i));
+        }
+        run_shutdown_hooks();
+        run_shutdown_hooks();
+        let result = order.lock().unwrap().clone();
+        println!("Expected: {:?} Got: {:?}", vec![3, 2, 1], result);
+        assert_eq!(
+            result,
+            vec![3, 2, 1],
+            "Hooks should run once in reverse order!"
+        );
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_thread_pool_tasks() {
+        use crate::config::config;
+        use crate::threading::thread_pool::{pool_size, spawn, wait};
+        use std::collections::HashSet;
+        let task_count = 32;
+        let handles: Vec<_> = (0..task_count)
+            .map(|i| {
+                spawn(move || {
+                    std::thread::sleep(std::time::Duration::from_millis(5));
+                    (i * 2, std::thread::current().id())
+                })
+            })
+            .collect();
+        let mut threads = HashSet::new();
+        let mut results = Vec::new();
+        for handle in handles {
+            let (value, thread) = wait(handle).unwrap();
+            results.push(value);
+            threads.insert(thread);
+        }
+        let expected: Vec<i32> = (0..task_count).map(|i| i * 2).collect();
+        println!(
+            "Pool size: {} Threads used: {} Results: {:?}",
+            pool_size(),
+            threads.len(),
+            results
+        );
+        assert_eq!(results, expected, "Not every task completed correctly!");
+        assert_eq!(
+            pool_size(),
+            config().thread_pool_size.max(1),
+            "Pool size should come from the configuration!"
+        );
+        assert!(
+            threads.len() <= pool_size(),
+            "Tasks ran on more threads than the pool holds!"
+        );
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_thread_pool_wait_inside_task() {
+        use crate::threading::thread_pool::{spawn, wait};
+        let outer = spawn(|| {
+            let inner = spawn(|| 5);
+            wait(inner)
+        });
+        let result = wait(outer).unwrap();
+        println!("Got: {:?}", result);
+        assert!(
+            matches!(result, Err(RUMError::Other(..))),
+            "Waiting inside a pool task should fail instead of panicking! Got {:?}!",
+            result
+        );
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_rumcache_shared_arc_insertion() {
+        use crate::cache::{get_or_set_from_cache, new_cache, LazyRUMCache};
+        use std::sync::Arc;
+        let cache: LazyRUMCache<RUMString, RUMString> = new_cache();
+        let first = Arc::clone(&cache);
+        let second = Arc::clone(&cache);
+        let upper = |k: &RUMString| RUMString::from(k.to_uppercase());
+        let a = get_or_set_from_cache(&first, &RUMString::from("msh"), upper);
+        let b = get_or_set_from_cache(&second, &RUMString::from("pid"), upper);
+        let c = get_or_set_from_cache(&second, &RUMString::from("msh"), |_| RUMString::from("x"));
+        let expected = (RUMString::from("MSH"), RUMString::from("PID"));
+        println!("Expected: {:?} Got: {:?}", expected, (&a, &b, &c));
+        assert_eq!(a, expected.0, "First clone returned the wrong value!");
+        assert_eq!(b, expected.1, "Second clone returned the wrong value!");
+        assert_eq!(
+            c, expected.0,
+            "Entry inserted from first clone was not shared!"
+        );
+        assert_eq!(
+            cache.lock().unwrap().len(),
+            2,
+            "Incorrect number of items in cache!"
+        );
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_with_cached_borrows_entry() {
+        use crate::cache::{new_cache, with_cached, LazyRUMCache};
+        let cache: LazyRUMCache<u32, Vec<RUMString>> = new_cache();
+        let build = |n: &u32| vec![RUMString::from("OBX"); *n as usize];
+        let first = with_cached(&cache, &3, build, |v| v.len());
+        let second = with_cached(&cache, &3, |_| Vec::new(), |v| v[0].clone());
+        println!(
+            "Expected: {:?} Got: {:?}",
+            (3, "OBX"),
+            (first, second.as_str())
+        );
+        assert_eq!(first, 3, "Miss did not build the entry!");
+        assert_eq!(second, "OBX", "Hit did not borrow the cached entry!");
+        assert_eq!(
+            cache.lock().unwrap().len(),
+            1,
+            "Incorrect number of items in cache!"
+        );
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_bounded_cache_evicts_least_recently_used() {
+        use crate::cache::BoundedRUMCache;
+        let mut cache: BoundedRUMCache<RUMString, u32> = BoundedRUMCache::new(3);
+        cache.insert(RUMString::from("MSH"), 1);
+        cache.insert(RUMString::from("PID"), 2);
+        cache.insert(RUMString::from("OBX"), 3);
+        cache.get(&RUMString::from("MSH"));
+        cache.get(&RUMString::from("OBX"));
+        let evicted = cache.insert(RUMString::from("EVN"), 4);
+        let expected = Some((RUMString::from("PID"), 2));
+        println!("Expected: {:?} Got: {:?}", expected, evicted);
+        assert_eq!(evicted, expected, "Wrong entry was evicted!");
+        assert!(
+            !cache.contains_key(&RUMString::from("PID")),
+            "Evicted key is still cached!"
+        );
+        for key in ["MSH", "OBX", "EVN"] {
+            assert!(
+                cache.contains_key(&RUMString::from(key)),
+                "Recently used key {} was dropped!",
+                key
+            );
+        }
+        assert_eq!(cache.len(), 3, "Cache size does not match limit!");
+        assert_eq!(cache.limit(), 3, "Cache limit changed!");
+        println!("Passed!")
+    }
+
+    #[test]
+    fn test_bounded_cache_fetch_bumps_recency() {
+        use crate::cache::new_bounded_cache;
+        let cache = new_bounded_cache::<u32, u32>(2);
+        rumtk_cache_fetch_bounded!(&cache, &1, |k| k * 10);
+        rumtk_cache_fetch_bounded!(&cache, &2, |k| k * 10);
+        let hit = rumtk_cache_fetch_bounded!(&cache, &1, |_| 0);
+        rumtk_cache_fetch_bounded!(&cache, &3, |k| k * 10);
+        let guard = cache.lock().unwrap();
+        println!("Expected: 10 Got: {}", hit);
+        assert_eq!(hit, 10, "Hit did not return cached value!");
+        assert!(guard.contains_key(&1), "Recently fetched key was evicted!");
+        assert!(!guard.contains_key(&2), "Oldest key survived eviction!");
+        assert!(guard.contains_key(&3), "New key was not inserted!");
+        assert_eq!(guard.len(), guard.limit(), "Cache grew past its limit!");
+        println!("Passed!")
+    }
+
     #[test]
     fn test_rumcache_insertion() {
         let mut cache: RUMCache<&str, CompactString> = RUMCache::with_capacity(5);
@@ -317,6 +3131,22 @@ mod tests {
         assert_eq!(&result, &expected, "{}", format_compact!("Task processing returned a different result than expected! Expected {:?} \nResults {:?}", &expected, &result));
     }
 
+    #[test]
+    fn test_resolve_task_error_kind() {
+        let rt = rumtk_init_threads!();
+        let handle = rt.spawn(async {
+            panic!("Task blew up!");
+        });
+        let result: RUMResult<()> = rumtk_resolve_task!(&rt, handle);
+        println!("Got: {:?}", result);
+        assert!(
+            matches!(result, Err(RUMError::Other(..))),
+            "Expected an Other error! Got {:?}!",
+            result
+        );
+        println!("Passed!")
+    }
+
     #[test]
     fn test_execute_job_macros() {
         let rt = rumtk_init_threads!();
@@ -699,6 +3529,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_deserialize_json_error_kind() {
+        #[derive(Serialize, Deserialize, PartialEq, Debug)]
+        struct MyStruct {
+            hello: RUMString,
+        }
+
+        let result: RUMResult<MyStruct> = rumtk_deserialize!("{\"hello\": 5}");
+        println!("Got: {:?}", result);
+        assert!(
+            matches!(result, Err(RUMError::Parse(..))),
+            "Expected a Parse error! Got {:?}!",
+            result
+        );
+        println!("Passed!")
+    }
+
     /*
     #[test]
     fn test_escape_unescape_json() {
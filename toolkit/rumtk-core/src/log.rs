@@ -17,4 +17,114 @@
  * License along with this library; if not, write to the Free Software
  * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
  */
+use crate::core::RUMResult;
+#[cfg(feature = "logging")]
+use crate::core::RUMError;
+#[cfg(feature = "logging")]
+use compact_str::format_compact;
+use std::fmt::Arguments;
 
+/**************************** Constants**************************************/
+/// Target used for every record emitted by the toolkit, so applications can filter on it.
+pub const LOG_TARGET: &str = "rumtk";
+
+/**************************** Types *****************************************/
+
+///
+/// Verbosity accepted by [init_logging]. Records less severe than the chosen level are dropped.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+///
+/// Minimal logger installed by [init_logging]. Writes `[LEVEL target] message` lines to stderr.
+///
+#[cfg(feature = "logging")]
+struct RUMLogger;
+
+#[cfg(feature = "logging")]
+impl ::log::Log for RUMLogger {
+    fn enabled(&self, metadata: &::log::Metadata) -> bool {
+        metadata.level() <= ::log::max_level()
+    }
+
+    fn log(&self, record: &::log::Record) {
+        if self.enabled(record.metadata()) {
+            eprintln!("[{} {}] {}", record.level(), record.target(), record.args());
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+#[cfg(feature = "logging")]
+static LOGGER: RUMLogger = RUMLogger;
+
+#[cfg(feature = "logging")]
+impl From<LogLevel> for ::log::LevelFilter {
+    fn from(level: LogLevel) -> Self {
+        match level {
+            LogLevel::Error => ::log::LevelFilter::Error,
+            LogLevel::Warn => ::log::LevelFilter::Warn,
+            LogLevel::Info => ::log::LevelFilter::Info,
+            LogLevel::Debug => ::log::LevelFilter::Debug,
+            LogLevel::Trace => ::log::LevelFilter::Trace,
+        }
+    }
+}
+
+/**************************** Helpers ***************************************/
+
+///
+/// Install a stderr logger for the toolkit's records at the given level. Requires the `logging`
+/// feature. Applications that already use a [log](https://docs.rs/log) compatible logger should
+/// skip this; the toolkit's records go to whichever logger is installed.
+///
+/// Fails if a logger is already installed. Without the `logging` feature this does nothing.
+///
+#[cfg(feature = "logging")]
+pub fn init_logging(level: LogLevel) -> RUMResult<()> {
+    match ::log::set_logger(&LOGGER) {
+        Ok(_) => {
+            ::log::set_max_level(level.into());
+            Ok(())
+        }
+        Err(e) => Err(RUMError::other(format_compact!(
+            "Failed to initialize logging because {}!",
+            e
+        ))),
+    }
+}
+
+#[cfg(not(feature = "logging"))]
+pub fn init_logging(_level: LogLevel) -> RUMResult<()> {
+    Ok(())
+}
+
+///
+/// Emit a debug record under [LOG_TARGET]. Compiles to nothing without the `logging` feature.
+///
+#[inline(always)]
+pub fn log_debug(args: Arguments) {
+    #[cfg(feature = "logging")]
+    ::log::debug!(target: LOG_TARGET, "{}", args);
+    #[cfg(not(feature = "logging"))]
+    let _ = args;
+}
+
+///
+/// Emit a warning record under [LOG_TARGET]. Compiles to nothing without the `logging` feature.
+///
+#[inline(always)]
+pub fn log_warn(args: Arguments) {
+    #[cfg(feature = "logging")]
+    ::log::warn!(target: LOG_TARGET, "{}", args);
+    #[cfg(not(feature = "logging"))]
+    let _ = args;
+}
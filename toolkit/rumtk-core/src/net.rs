@@ -25,7 +25,7 @@
 /// The types here should simplify implementation of higher level layers and protocols.
 ///
 pub mod tcp {
-    use crate::core::RUMResult;
+    use crate::core::{RUMError, RUMResult, RUMResultExt};
     use crate::strings::RUMString;
     use crate::threading::thread_primitives::{SafeTaskArgs, SafeTokioRuntime, TaskResult};
     use crate::threading::threading_functions::get_default_system_thread_count;
@@ -38,7 +38,7 @@ pub mod tcp {
     use std::collections::VecDeque;
     use std::sync::Arc;
     use tokio::io;
-    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt, ErrorKind};
     pub use tokio::net::{TcpListener, TcpStream};
     pub use tokio::sync::{
         Mutex as AsyncMutex, MutexGuard as AsyncMutexGuard, RwLock as AsyncRwLock, RwLockReadGuard,
@@ -78,10 +78,9 @@ pub mod tcp {
                     socket,
                     disconnected: false,
                 }),
-                Err(e) => Err(format_compact!(
-                    "Unable to connect to {} because {}",
-                    &addr.as_str(),
-                    &e
+                Err(e) => Err(RUMError::io(
+                    e.kind(),
+                    format_compact!("Unable to connect to {} because {}", &addr.as_str(), &e),
                 )),
             }
         }
@@ -102,9 +101,12 @@ pub mod tcp {
         ///
         pub async fn send(&mut self, msg: &RUMNetMessage) -> RUMResult<()> {
             if self.is_disconnected() {
-                return Err(format_compact!(
-                    "{} disconnected!",
-                    &self.socket.peer_addr().unwrap().to_compact_string()
+                return Err(RUMError::io(
+                    ErrorKind::NotConnected,
+                    format_compact!(
+                        "{} disconnected!",
+                        &self.socket.peer_addr().unwrap().to_compact_string()
+                    ),
                 ));
             }
 
@@ -112,10 +114,13 @@ pub mod tcp {
                 Ok(_) => Ok(()),
                 Err(e) => {
                     self.disconnect();
-                    Err(format_compact!(
-                        "Unable to send message to {} because {}",
-                        &self.socket.local_addr().unwrap().to_compact_string(),
-                        &e
+                    Err(RUMError::io(
+                        e.kind(),
+                        format_compact!(
+                            "Unable to send message to {} because {}",
+                            &self.socket.local_addr().unwrap().to_compact_string(),
+                            &e
+                        ),
                     ))
                 }
             }
@@ -129,9 +134,12 @@ pub mod tcp {
             let mut msg = RUMNetMessage::new();
 
             if self.is_disconnected() {
-                return Err(format_compact!(
-                    "{} disconnected!",
-                    &self.socket.peer_addr().unwrap().to_compact_string()
+                return Err(RUMError::io(
+                    ErrorKind::NotConnected,
+                    format_compact!(
+                        "{} disconnected!",
+                        &self.socket.peer_addr().unwrap().to_compact_string()
+                    ),
                 ));
             }
 
@@ -151,9 +159,12 @@ pub mod tcp {
                 Ok(n) => match n {
                     0 => {
                         self.disconnect();
-                        Err(format_compact!(
-                            "Received 0 bytes from {}! It might have disconnected!",
-                            &self.socket.peer_addr().unwrap().to_compact_string()
+                        Err(RUMError::io(
+                            ErrorKind::UnexpectedEof,
+                            format_compact!(
+                                "Received 0 bytes from {}! It might have disconnected!",
+                                &self.socket.peer_addr().unwrap().to_compact_string()
+                            ),
                         ))
                     }
                     MESSAGE_BUFFER_SIZE => Ok((RUMNetMessage::from(buf), true)),
@@ -164,10 +175,13 @@ pub mod tcp {
                 }
                 Err(e) => {
                     self.disconnect();
-                    Err(format_compact!(
-                        "Error receiving message from {} because {}",
-                        &self.socket.peer_addr().unwrap().to_compact_string(),
-                        &e
+                    Err(RUMError::io(
+                        e.kind(),
+                        format_compact!(
+                            "Error receiving message from {} because {}",
+                            &self.socket.peer_addr().unwrap().to_compact_string(),
+                            &e
+                        ),
                     ))
                 }
             }
@@ -177,24 +191,33 @@ pub mod tcp {
             let mut buf: [u8; 1] = [0; 1];
 
             if self.is_disconnected() {
-                return Err(format_compact!(
-                    "{} disconnected!",
-                    &self.socket.peer_addr().unwrap().to_compact_string()
+                return Err(RUMError::io(
+                    ErrorKind::NotConnected,
+                    format_compact!(
+                        "{} disconnected!",
+                        &self.socket.peer_addr().unwrap().to_compact_string()
+                    ),
                 ));
             }
 
             match self.socket.peek(&mut buf).await {
                 Ok(n) => match n {
-                    0 => Err(format_compact!(
-                        "Received 0 bytes from {}! It might have disconnected!",
-                        &self.socket.peer_addr().unwrap().to_compact_string()
+                    0 => Err(RUMError::io(
+                        ErrorKind::UnexpectedEof,
+                        format_compact!(
+                            "Received 0 bytes from {}! It might have disconnected!",
+                            &self.socket.peer_addr().unwrap().to_compact_string()
+                        ),
                     )),
                     _ => Ok(true),
                 },
-                Err(e) => Err(format_compact!(
-                    "Error receiving message from {} because {}. It might have disconnected!",
-                    &self.socket.peer_addr().unwrap().to_compact_string(),
-                    &e
+                Err(e) => Err(RUMError::io(
+                    e.kind(),
+                    format_compact!(
+                        "Error receiving message from {} because {}. It might have disconnected!",
+                        &self.socket.peer_addr().unwrap().to_compact_string(),
+                        &e
+                    ),
                 )),
             }
         }
@@ -315,10 +338,9 @@ pub mod tcp {
             let tcp_listener_handle = match TcpListener::bind(addr.as_str()).await {
                 Ok(listener) => listener,
                 Err(e) => {
-                    return Err(format_compact!(
-                        "Unable to bind to {} because {}",
-                        &addr.as_str(),
-                        &e
+                    return Err(RUMError::io(
+                        e.kind(),
+                        format_compact!("Unable to bind to {} because {}", &addr.as_str(), &e),
                     ))
                 }
             };
@@ -468,7 +490,7 @@ pub mod tcp {
                     let client = RUMClient::accept(socket).await?;
                     let client_id = match client.get_address(false).await {
                         Some(client_id) => client_id,
-                        None => return Err(format_compact!("Accepted client returned no peer address. This should not be happening!"))
+                        None => return Err(RUMError::io(ErrorKind::AddrNotAvailable, format_compact!("Accepted client returned no peer address. This should not be happening!")))
                     };
                     let mut client_list = clients.write().await;
                     RUMServer::register_queue(&tx_in, &client_id).await;
@@ -476,9 +498,9 @@ pub mod tcp {
                     client_list.insert(client_id, SafeClient::new(AsyncRwLock::new(client)));
                     Ok(())
                 }
-                Err(e) => Err(format_compact!(
-                    "Error accepting incoming client! Error: {}",
-                    e
+                Err(e) => Err(RUMError::io(
+                    e.kind(),
+                    format_compact!("Error accepting incoming client! Error: {}", e),
                 )),
             }
         }
@@ -496,12 +518,9 @@ pub mod tcp {
                     None => continue,
                 };
                 for msg in messages.iter() {
-                    match RUMServer::send(client, msg).await {
-                        Ok(_) => (),
-                        Err(e) => {
-                            return Err(format_compact!("{}... Dropping client...", e));
-                        }
-                    };
+                    RUMServer::send(client, msg)
+                        .await
+                        .with_context(|| format_compact!("Dropping client {}", client_id))?;
                 }
             }
 
@@ -556,9 +575,12 @@ pub mod tcp {
             }
 
             if !disconnected_clients.is_empty() {
-                return Err(format_compact!(
-                    "The following clients have disconnected and thus will be removed! {:?}",
-                    disconnected_clients
+                return Err(RUMError::io(
+                    ErrorKind::NotConnected,
+                    format_compact!(
+                        "The following clients have disconnected and thus will be removed! {:?}",
+                        disconnected_clients
+                    ),
                 ));
             }
 
@@ -580,9 +602,9 @@ pub mod tcp {
             let mut queue = match queues.get_mut(client) {
                 Some(queue) => queue,
                 None => {
-                    return Err(format_compact!("Attempted to queue message for non-connected \
+                    return Err(RUMError::other(format_compact!("Attempted to queue message for non-connected \
                     client! Make sure client was connected! The client might have been disconnected. \
-                    Client: {}", &client));
+                    Client: {}", &client)));
                 }
             };
             let mut locked_queue = queue.lock().await;
@@ -643,7 +665,10 @@ pub mod tcp {
         ) -> RUMResult<SafeClient> {
             match clients.read().await.get(client) {
                 Some(client) => Ok(client.clone()),
-                _ => Err(format_compact!("Client {} not found!", client)),
+                _ => Err(RUMError::other(format_compact!(
+                    "Client {} not found!",
+                    client
+                ))),
             }
         }
 
@@ -699,7 +724,10 @@ pub mod tcp {
         ) -> RUMResult<()> {
             let mut queue = self.tx_out.lock().await;
             if !queue.contains_key(client_id) {
-                return Err(format_compact!("No client with id {} found!", &client_id));
+                return Err(RUMError::other(format_compact!(
+                    "No client with id {} found!",
+                    &client_id
+                )));
             }
             let mut queue = queue[client_id].lock().await;
             queue.push_back(msg);
@@ -755,7 +783,7 @@ pub mod tcp {
     /// receive methods implemented in [RUMClient].
     ///
     pub struct RUMClientHandle {
-        runtime: &'static SafeTokioRuntime,
+        runtime: SafeTokioRuntime,
         client: SafeClient,
     }
 
@@ -832,9 +860,9 @@ pub mod tcp {
             let (ip, port) = match lock_future.get(0) {
                 Some((ip, port)) => (ip, port),
                 None => {
-                    return Err(format_compact!(
+                    return Err(RUMError::other(format_compact!(
                         "No IP address or port provided for connection!"
-                    ))
+                    )))
                 }
             };
             Ok(vec![RUMClient::connect(ip, *port).await?])
@@ -860,7 +888,7 @@ pub mod tcp {
     /// own message.
     ///
     pub struct RUMServerHandle {
-        runtime: &'static SafeTokioRuntime,
+        runtime: SafeTokioRuntime,
         server: SafeServer,
     }
 
@@ -1036,9 +1064,9 @@ pub mod tcp {
             let (ip, port) = match locked_args.get(0) {
                 Some((ip, port)) => (ip, port),
                 None => {
-                    return Err(format_compact!(
+                    return Err(RUMError::other(format_compact!(
                         "No IP address or port provided for connection!"
-                    ))
+                    )))
                 }
             };
             Ok(vec![RUMServer::new(ip, *port).await?])
@@ -0,0 +1,119 @@
+/*
+ * rumtk attempts to implement HL7 and medical protocols for interoperability in medicine.
+ * This toolkit aims to be reliable, simple, performant, and standards compliant.
+ * Copyright (C) 2025  Luis M. Santos, M.D.
+ *
+ * This library is free software; you can redistribute it and/or
+ * modify it under the terms of the GNU Lesser General Public
+ * License as published by the Free Software Foundation; either
+ * version 2.1 of the License, or (at your option) any later version.
+ *
+ * This library is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+ * Lesser General Public License for more details.
+ *
+ * You should have received a copy of the GNU Lesser General Public
+ * License along with this library; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
+ */
+use crate::cache::RUMCache;
+use crate::strings::RUMString;
+use once_cell::sync::Lazy;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/**************************** Types *****************************************/
+
+///
+/// Aggregated timings recorded by [instrument] for one label.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimingStats {
+    pub label: RUMString,
+    pub count: u64,
+    pub total: Duration,
+    pub min: Duration,
+    pub max: Duration,
+}
+
+type TimingRegistry = RUMCache<RUMString, TimingStats>;
+
+/**************************** Globals ***************************************/
+static TIMINGS: Lazy<Mutex<TimingRegistry>> = Lazy::new(|| Mutex::new(TimingRegistry::default()));
+
+/**************************** Helpers ***************************************/
+
+impl TimingStats {
+    fn new(label: &str, elapsed: Duration) -> TimingStats {
+        TimingStats {
+            label: RUMString::from(label),
+            count: 1,
+            total: elapsed,
+            min: elapsed,
+            max: elapsed,
+        }
+    }
+
+    fn record(&mut self, elapsed: Duration) {
+        self.count += 1;
+        self.total += elapsed;
+        self.min = self.min.min(elapsed);
+        self.max = self.max.max(elapsed);
+    }
+
+    pub fn mean(&self) -> Duration {
+        Duration::from_nanos((self.total.as_nanos() / self.count as u128) as u64)
+    }
+}
+
+///
+/// Run `f`, record how long it took under `label` in the process wide timing registry, and
+/// return its result. Read the registry with [timing_report].
+///
+/// This is meant for cheap, always-on profiling of Rust code paths. The only overhead is reading
+/// the clock twice and briefly locking the registry.
+///
+/// # Example
+/// ```
+///  use rumtk_core::profiling::{instrument, timing_report};
+///  let sum = instrument("sum_fields", || (1..=10).sum::<u32>());
+///  assert_eq!(55, sum);
+///  let report = timing_report();
+///  let stats = report.iter().find(|s| s.label == "sum_fields").unwrap();
+///  assert_eq!(1, stats.count);
+///```
+///
+pub fn instrument<F, R>(label: &str, f: F) -> R
+where
+    F: FnOnce() -> R,
+{
+    let start = Instant::now();
+    let result = f();
+    let elapsed = start.elapsed();
+    let mut timings = TIMINGS.lock().unwrap_or_else(|e| e.into_inner());
+    match timings.get_mut(label) {
+        Some(stats) => stats.record(elapsed),
+        None => {
+            timings.insert(RUMString::from(label), TimingStats::new(label, elapsed));
+        }
+    }
+    result
+}
+
+///
+/// Snapshot of every label recorded by [instrument], sorted by label.
+///
+pub fn timing_report() -> Vec<TimingStats> {
+    let timings = TIMINGS.lock().unwrap_or_else(|e| e.into_inner());
+    let mut report: Vec<TimingStats> = timings.values().cloned().collect();
+    report.sort_by(|a, b| a.label.cmp(&b.label));
+    report
+}
+
+///
+/// Forget every timing recorded so far.
+///
+pub fn reset_timings() {
+    TIMINGS.lock().unwrap_or_else(|e| e.into_inner()).clear();
+}
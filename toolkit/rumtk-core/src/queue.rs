@@ -31,7 +31,7 @@ pub mod queue {
 
     pub struct TaskQueue<R> {
         tasks: AsyncTaskHandles<R>,
-        runtime: &'static SafeTokioRuntime,
+        runtime: SafeTokioRuntime,
     }
 
     impl<R> TaskQueue<R>
@@ -21,18 +21,26 @@
 pub mod python_utils {
     use std::ffi::{CString, OsStr};
     use std::fmt::Debug;
-    use std::fs::read_to_string;
-    use std::path::Path;
+    use std::fs::{metadata, read_to_string};
+    use std::path::{Component, Path, PathBuf};
+    use std::time::SystemTime;
 
-    use crate::core::RUMResult;
+    use crate::cache::{new_cache, LazyRUMCache, RUMCache};
+    use crate::config::config;
+    use crate::core::{RUMError, RUMResult, RUMResultExt};
     use crate::strings::RUMString;
     use compact_str::format_compact;
 
+    use pyo3::conversion::FromPyObjectOwned;
     use pyo3::prelude::*;
-    use pyo3::types::{PyList, PyTuple};
+    use pyo3::types::{PyDict, PyList, PyTuple};
+    use pyo3::IntoPyObjectExt;
 
     pub type RUMPyArgs = Py<PyTuple>;
     pub type RUMPyList = Py<PyList>;
+    pub type RUMPyKwargs = Vec<(RUMString, RUMPyAny)>;
+    pub type RUMPyDict = Py<PyDict>;
+    pub type RUMPyStringMap = RUMCache<RUMString, RUMString>;
     pub type RUMPyResultList = Vec<RUMString>;
     pub type RUMPyModule = Py<PyModule>;
     pub type RUMPyTuple = Py<PyTuple>;
@@ -41,36 +49,104 @@ pub mod python_utils {
     pub type RUMPython<'py> = Python<'py>;
     pub type RUMPyResult<T> = PyResult<T>;
 
+    ///
+    /// Compiled module kept by [py_load_cached] along with the file's modification time when it was
+    /// loaded.
+    ///
+    pub struct RUMPyCachedModule {
+        module: RUMPyModule,
+        modified: Option<SystemTime>,
+    }
+    pub type RUMPyModuleCache = LazyRUMCache<RUMString, RUMPyCachedModule>;
+
+    static MODULE_CACHE: RUMPyModuleCache = new_cache();
+
+    ///
+    /// Converts a Python exception into [RUMError::Python] so `?` works on pyo3 results. Same
+    /// message as [py_error]. This has no GIL token to work with, so it attaches to the
+    /// interpreter itself; prefer [py_error] when a token is at hand. Nothing is logged here,
+    /// because the caller may still recover from the error.
+    ///
+    impl From<PyErr> for RUMError {
+        fn from(e: PyErr) -> Self {
+            Python::attach(|py| py_error(py, &e))
+        }
+    }
+
+    ///
+    /// Converts a Python exception into [RUMError::Python]. The message starts with the exception
+    /// type and value (`ValueError: bad MRN`), followed by the Python traceback when one is
+    /// available. See [format_py_err].
+    ///
+    pub fn py_error(py: Python, err: &PyErr) -> RUMError {
+        RUMError::python(format_py_err(py, err))
+    }
+
+    ///
+    /// Render a Python exception as `Type: value`, followed by the formatted traceback when one is
+    /// available.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    ///     use pyo3::Python;
+    ///     use pyo3::exceptions::PyValueError;
+    ///     use crate::rumtk_core::scripting::python_utils::format_py_err;
+    ///
+    ///     Python::attach( |py| {
+    ///             let err = PyValueError::new_err("bad MRN");
+    ///             assert_eq!(format_py_err(py, &err), "ValueError: bad MRN", "Wrong error rendering!");
+    ///         }
+    ///     )
+    /// ```
+    ///
+    pub fn format_py_err(py: Python, err: &PyErr) -> RUMString {
+        let exception_type = match err.get_type(py).name() {
+            Ok(name) => name.to_string(),
+            Err(_) => String::from("Exception"),
+        };
+        let mut message = format_compact!("{}: {}", exception_type, err.value(py));
+        if let Some(Ok(traceback)) = err.traceback(py).map(|tb| tb.format()) {
+            message.push('\n');
+            message.push_str(traceback.trim_end());
+        }
+        message
+    }
+
     fn string_to_cstring(data: &str) -> RUMResult<CString> {
         match CString::new(data) {
             Ok(code) => Ok(code),
-            Err(e) => Err(format_compact!(
+            Err(e) => Err(RUMError::parse(format_compact!(
                 "Could not cast Python code string to a C string!"
-            )),
+            ))),
         }
     }
 
     fn ostring_to_cstring(data: &OsStr) -> RUMResult<CString> {
         let data_str = match data.to_str() {
             Some(s) => s,
-            None => return Err(format_compact!("Could not cast OsStr to a str!")),
+            None => {
+                return Err(RUMError::parse(format_compact!(
+                    "Could not cast OsStr to a str!"
+                )))
+            }
         };
         match CString::new(data_str) {
             Ok(code) => Ok(code),
-            Err(e) => Err(format_compact!(
+            Err(e) => Err(RUMError::parse(format_compact!(
                 "Could not cast Python code string to a C string because {:#?}!",
                 e
-            )),
+            ))),
         }
     }
 
     pub fn py_list_to_tuple(py: RUMPython, py_list: &RUMPyList) -> RUMResult<RUMPyTuple> {
         match PyTuple::new(py, py_list.bind(py).iter()) {
             Ok(py_args) => Ok(py_args.into()),
-            Err(e) => Err(format_compact!(
-                "Failed to convert arguments from PyList to PyTuple! Reason: {:?}",
-                e
-            )),
+            Err(e) => Err(RUMError::python(format_compact!(
+                "Failed to convert arguments from PyList to PyTuple! Reason: {}",
+                format_py_err(py, &e)
+            ))),
         }
     }
 
@@ -101,13 +177,13 @@ pub mod python_utils {
     {
         match PyList::new(py, args.clone()) {
             Ok(py_args) => Ok(py_args.into()),
-            Err(e) => Err(
+            Err(e) => Err(RUMError::python(
                 format_compact!(
-                    "Failed to convert arguments into a Python Object for transfer to Interpreter! Arguments: {:?} Reason: {:?}",
+                    "Failed to convert arguments into a Python Object for transfer to Interpreter! Arguments: {:?} Reason: {}",
                     &args,
-                    e.to_string()
+                    format_py_err(py, &e)
                 )
-            )
+            ))
         }
     }
 
@@ -177,13 +253,54 @@ pub mod python_utils {
     {
         match py_args.bind(py).append((*arg).clone()) {
             Ok(_) => Ok(()),
-            Err(e) => Err(
+            Err(e) => Err(RUMError::python(
                 format_compact!(
-                    "Failed to convert argument into a Python Object for transfer to Interpreter! Argument: {:?} Reason: {:?}",
+                    "Failed to convert argument into a Python Object for transfer to Interpreter! Argument: {:?} Reason: {}",
                     &arg,
-                    e.to_string()
+                    format_py_err(py, &e)
                 )
-            )
+            ))
+        }
+    }
+
+    ///
+    /// Push keyword argument `key=arg` into a [RUMPyKwargs] collection. The value is converted the
+    /// same way [py_push_arg] converts positional arguments.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    ///     use pyo3::Python;
+    ///     use crate::rumtk_core::scripting::python_utils::{py_push_kwarg, RUMPyKwargs};
+    ///
+    ///     Python::attach( |py| {
+    ///             let mut kwargs = RUMPyKwargs::new();
+    ///             py_push_kwarg(py, &mut kwargs, "scale", &2.0).unwrap();
+    ///             assert_eq!(kwargs[0].0, "scale", "Keyword was not stored!");
+    ///         }
+    ///     )
+    /// ```
+    ///
+    pub fn py_push_kwarg<'a, 'py, T>(
+        py: RUMPython<'py>,
+        kwargs: &mut RUMPyKwargs,
+        key: &str,
+        arg: &T,
+    ) -> RUMResult<()>
+    where
+        T: FromPyObject<'a, 'py> + IntoPyObject<'py> + Debug + Clone,
+    {
+        match (*arg).clone().into_py_any(py) {
+            Ok(value) => {
+                kwargs.push((RUMString::from(key), value));
+                Ok(())
+            }
+            Err(e) => Err(RUMError::python(format_compact!(
+                "Failed to convert keyword argument {} into a Python Object for transfer to Interpreter! Argument: {:?} Reason: {}",
+                key,
+                &arg,
+                format_py_err(py, &e)
+            ))),
         }
     }
 
@@ -202,16 +319,124 @@ pub mod python_utils {
             let py_list: Vec<String> = match pyargs.extract(py) {
                 Ok(list) => list,
                 Err(e) => {
-                    return Err(format_compact!(
-                        "Could not extract list from Python args! Reason => {:?}",
-                        e
-                    ));
+                    return Err(RUMError::python(format_compact!(
+                        "Could not extract list from Python args! Reason => {}",
+                        format_py_err(py, &e)
+                    )));
                 }
             };
             Ok(string_vector_to_rumstring_vector(&py_list))
         })
     }
 
+    ///
+    /// Extract a Python `dict` of strings into a [RUMPyStringMap]. A key or value that is not a
+    /// `str` is an error naming the offending entry. Nothing is stringified implicitly.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    ///     use compact_str::format_compact;
+    ///     use pyo3::Python;
+    ///     use crate::rumtk_core::scripting::python_utils::{py_exec_module, py_extract_string_map, py_load, py_new_args};
+    ///     use crate::rumtk_core::strings::RUMString;
+    ///     use uuid::Uuid;
+    ///
+    ///     let module_contents = "def test():\n\treturn {'patient': 'Doe', 'mrn': '123'}";
+    ///     let fpath: RUMString = format_compact!("/tmp/{}.py", Uuid::new_v4());
+    ///     std::fs::write(&fpath, module_contents.as_bytes()).expect("Failure to write test module.");
+    ///
+    ///     let result = Python::attach( |py| {
+    ///         let pymod = py_load(py, &fpath).expect("Failure to load module!");
+    ///         let result = py_exec_module(py, &pymod, "test", &py_new_args(py)).unwrap();
+    ///         py_extract_string_map(py, &result).unwrap()
+    ///     });
+    ///     std::fs::remove_file(&fpath).unwrap();
+    ///
+    ///     assert_eq!(result.len(), 2, "Wrong number of entries extracted!");
+    ///     assert_eq!(result.get("patient").unwrap(), "Doe", "Wrong patient extracted!");
+    ///     assert_eq!(result.get("mrn").unwrap(), "123", "Wrong MRN extracted!");
+    /// ```
+    ///
+    pub fn py_extract_string_map(py: Python, pyresult: &RUMPyAny) -> RUMResult<RUMPyStringMap> {
+        let pydict = match pyresult.bind(py).cast::<PyDict>() {
+            Ok(pydict) => pydict.clone(),
+            Err(e) => {
+                return Err(RUMError::python(format_compact!(
+                    "Could not extract map from Python result! Reason => {}",
+                    e
+                )));
+            }
+        };
+        let mut map = RUMPyStringMap::with_capacity(pydict.len());
+        for (key, value) in pydict.iter() {
+            let key: String = match key.extract() {
+                Ok(key) => key,
+                Err(_) => {
+                    return Err(RUMError::python(format_compact!(
+                        "Python dict key {} is not a string!",
+                        key.repr().map_err(|e| py_error(py, &e))?
+                    )));
+                }
+            };
+            let value: String = match value.extract() {
+                Ok(value) => value,
+                Err(_) => {
+                    return Err(RUMError::python(format_compact!(
+                        "Python dict value {} for key {:?} is not a string!",
+                        value.repr().map_err(|e| py_error(py, &e))?,
+                        key
+                    )));
+                }
+            };
+            map.insert(RUMString::from(key), RUMString::from(value));
+        }
+        Ok(map)
+    }
+
+    ///
+    /// Build a Python `dict` from a [RUMPyStringMap], e.g. to pass it as an argument to a script.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    ///     use compact_str::format_compact;
+    ///     use pyo3::Python;
+    ///     use crate::rumtk_core::scripting::python_utils::{py_build_map, py_exec_module, py_extract_any, py_load, py_new_args, py_push_arg, RUMPyStringMap};
+    ///     use crate::rumtk_core::strings::RUMString;
+    ///     use uuid::Uuid;
+    ///
+    ///     let module_contents = "def test(fields):\n\treturn fields['patient'] + '^' + fields['mrn']";
+    ///     let fpath: RUMString = format_compact!("/tmp/{}.py", Uuid::new_v4());
+    ///     std::fs::write(&fpath, module_contents.as_bytes()).expect("Failure to write test module.");
+    ///
+    ///     let mut fields = RUMPyStringMap::default();
+    ///     fields.insert(RUMString::from("patient"), RUMString::from("Doe"));
+    ///     fields.insert(RUMString::from("mrn"), RUMString::from("123"));
+    ///
+    ///     let result: String = Python::attach( |py| {
+    ///         let pymod = py_load(py, &fpath).expect("Failure to load module!");
+    ///         let mut args = py_new_args(py);
+    ///         let pydict = py_build_map(py, &fields).unwrap().into_bound(py);
+    ///         py_push_arg(py, &mut args, &pydict).unwrap();
+    ///         let result = py_exec_module(py, &pymod, "test", &args).unwrap();
+    ///         py_extract_any(py, &result).unwrap()
+    ///     });
+    ///     std::fs::remove_file(&fpath).unwrap();
+    ///
+    ///     assert_eq!(result, "Doe^123", "Script did not read the map back!");
+    /// ```
+    ///
+    pub fn py_build_map(py: Python, map: &RUMPyStringMap) -> RUMResult<RUMPyDict> {
+        let pydict = PyDict::new(py);
+        for (key, value) in map {
+            pydict
+                .set_item(key.as_str(), value.as_str())
+                .map_err(|e| py_error(py, &e))?;
+        }
+        Ok(pydict.unbind())
+    }
+
     ///
     /// Extract value returned from functions and modules via a `PyAny` object.
     ///
@@ -271,10 +496,10 @@ pub mod python_utils {
                 let val = r;
                 Ok(val)
             }
-            Err(e) => Err(format_compact!(
+            Err(e) => Err(RUMError::python(format_compact!(
                 "Could not extract vector from Python result! Reason => {:?}",
                 e
-            )),
+            ))),
         }
     }
 
@@ -303,41 +528,137 @@ pub mod python_utils {
     /// ```
     ///
     pub fn py_load(py: Python, fpath: &str) -> RUMResult<RUMPyModule> {
+        py_add_search_paths(py, &config().python_search_paths)?;
         let pypath = Path::new(fpath);
-        let pycode = match read_to_string(fpath) {
-            Ok(code) => string_to_cstring(&code)?,
-            Err(e) => {
-                return Err(format_compact!(
-                    "Unable to read Python file {}. Is it valid?",
-                    &fpath
-                ));
-            }
-        };
+        let source = read_to_string(fpath)
+            .map_err(RUMError::from)
+            .with_context(|| format_compact!("reading Python module {}", fpath))?;
+        let pycode = string_to_cstring(&source)?;
         let filename = match pypath.file_name() {
             Some(name) => ostring_to_cstring(name)?,
             None => {
-                return Err(format_compact!("Invalid Python module path {}!", &fpath));
+                return Err(RUMError::parse(format_compact!(
+                    "Invalid Python module path {}!",
+                    &fpath
+                )));
             }
         };
         let modname = match pypath.file_stem() {
             Some(name) => ostring_to_cstring(name)?,
             None => {
-                return Err(format_compact!("Invalid Python module path {}!", &fpath));
+                return Err(RUMError::parse(format_compact!(
+                    "Invalid Python module path {}!",
+                    &fpath
+                )));
             }
         };
         let pymod = match PyModule::from_code(py, pycode.as_c_str(), &filename, &modname) {
             Ok(pymod) => pymod,
             Err(e) => {
-                return Err(format_compact!(
-                    "Failed to load Python module {} because of {:#?}!",
+                return Err(RUMError::python(format_compact!(
+                    "Failed to load Python module {} because of {}",
                     &fpath,
-                    e
-                ));
+                    format_py_err(py, &e)
+                )));
             }
         };
         Ok(pymod.into())
     }
 
+    ///
+    /// Same as [py_load], but the compiled module is cached by file path. Later calls return the
+    /// cached module as long as the file's modification time has not changed.
+    ///
+    /// Pass `check_mtime = false` to skip the `stat` call when you know the file never changes.
+    /// The cached module is then returned as is.
+    ///
+    /// The cache key is the path with `.` components removed, so `./x.py` and `x.py` share one
+    /// entry. The path is not canonicalized: `..` components, symlinks and absolute versus
+    /// relative spellings of the same file are cached separately.
+    ///
+    /// ## Example Usage
+    ///
+    /// ```
+    ///     use compact_str::format_compact;
+    ///     use pyo3::Python;
+    ///     use crate::rumtk_core::scripting::python_utils::{py_load_cached, RUMPyModule};
+    ///     use crate::rumtk_core::strings::RUMString;
+    ///     use uuid::Uuid;
+    ///
+    ///     let fpath: RUMString = format_compact!("/tmp/{}.py", Uuid::new_v4());
+    ///     std::fs::write(&fpath, b"def test():\n\treturn 'Hello'").expect("Failure to write test module.");
+    ///
+    ///     Python::attach(|py| {
+    ///         let first: RUMPyModule = py_load_cached(py, &fpath, true).expect("Failure to load module!");
+    ///         let second: RUMPyModule = py_load_cached(py, &fpath, true).expect("Failure to load module!");
+    ///         assert!(first.is(&second), "Module was compiled twice!");
+    ///     });
+    ///     std::fs::remove_file(&fpath).unwrap()
+    /// ```
+    ///
+    pub fn py_load_cached(py: Python, fpath: &str, check_mtime: bool) -> RUMResult<RUMPyModule> {
+        let key = module_cache_key(fpath);
+        let modified = match check_mtime {
+            true => Some(
+                metadata(fpath)
+                    .and_then(|m| m.modified())
+                    .map_err(RUMError::from)
+                    .with_context(|| format_compact!("reading Python module {}", fpath))?,
+            ),
+            false => None,
+        };
+        {
+            let cache = MODULE_CACHE.lock().unwrap_or_else(|e| e.into_inner());
+            if let Some(cached) = cache.get(&key) {
+                if !check_mtime || cached.modified == modified {
+                    return Ok(cached.module.clone_ref(py));
+                }
+            }
+        }
+        // Compile without holding the lock so other threads waiting on it do not block the GIL.
+        let module = py_load(py, fpath)?;
+        let modified = match modified {
+            Some(modified) => Some(modified),
+            None => metadata(fpath).and_then(|m| m.modified()).ok(),
+        };
+        let mut cache = MODULE_CACHE.lock().unwrap_or_else(|e| e.into_inner());
+        cache.insert(
+            key,
+            RUMPyCachedModule {
+                module: module.clone_ref(py),
+                modified,
+            },
+        );
+        Ok(module)
+    }
+
+    fn module_cache_key(fpath: &str) -> RUMString {
+        let path: PathBuf = Path::new(fpath)
+            .components()
+            .filter(|component| *component != Component::CurDir)
+            .collect();
+        RUMString::from(path.to_string_lossy())
+    }
+
+    ///
+    /// Append each directory in `paths` to Python's `sys.path`, unless it is already there.
+    ///
+    pub fn py_add_search_paths(py: Python, paths: &[RUMString]) -> RUMResult<()> {
+        if paths.is_empty() {
+            return Ok(());
+        }
+        let append = || -> PyResult<()> {
+            let sys_path = py.import("sys")?.getattr("path")?;
+            for path in paths {
+                if !sys_path.contains(path.as_str())? {
+                    sys_path.call_method1("append", (path.as_str(),))?;
+                }
+            }
+            Ok(())
+        };
+        append().map_err(|e| py_error(py, &e))
+    }
+
     ///
     /// Function for executing a python module's function.
     /// If you set the argument `func_name` to an empty string, `py_exec` will do nothing. Allegedly,
@@ -345,6 +666,9 @@ pub mod python_utils {
     ///
     /// It is recommended you have a function to call from the module!!!
     ///
+    /// If the function raises, the error prints as `executing Python function <name>: ` followed
+    /// by the exception and its traceback (see [format_py_err]).
+    ///
     /// # Examples
     ///
     /// ## Executing Function Within Module
@@ -408,29 +732,165 @@ pub mod python_utils {
         args: &RUMPyList,
     ) -> RUMResult<RUMPyAny> {
         if !func_name.is_empty() {
-            let pyfunc: RUMPyFunction = match pymod.getattr(py, func_name) {
-                Ok(f) => f,
-                Err(e) => {
-                    return Err(format_compact!(
-                        "No function named {} found in module! Error: {:#?}",
-                        &func_name,
-                        e
-                    ));
-                }
-            };
-            match pyfunc.call1(py, py_list_to_tuple(py, args)?) {
-                Ok(r) => Ok(r),
-                Err(e) => Err(format_compact!(
-                    "An error occurred executing Python function {}. Error: {}",
-                    &func_name,
-                    e
-                )),
-            }
+            let pyfunc: RUMPyFunction = pymod
+                .getattr(py, func_name)
+                .map_err(|e| py_error(py, &e))
+                .with_context(|| format_compact!("executing Python function {}", func_name))?;
+            pyfunc
+                .call1(py, py_list_to_tuple(py, args)?)
+                .map_err(|e| py_error(py, &e))
+                .with_context(|| format_compact!("executing Python function {}", func_name))
         } else {
             Ok(py_new_args(py).into_any())
         }
     }
 
+    ///
+    /// Same as [py_exec_module], but also passes keyword arguments to the function. With an empty
+    /// `kwargs`, this behaves exactly like [py_exec_module].
+    ///
+    /// ## Example
+    ///
+    /// ```
+    ///     use compact_str::format_compact;
+    ///     use pyo3::Python;
+    ///     use crate::rumtk_core::scripting::python_utils::{py_exec_module_kwargs, py_extract_any, py_load, py_new_args, py_push_arg, py_push_kwarg, RUMPyKwargs};
+    ///     use crate::rumtk_core::strings::RUMString;
+    ///     use uuid::Uuid;
+    ///
+    ///     let module_contents = "def test(a, *, scale=1.0):\n\treturn a*scale";
+    ///     let fpath: RUMString = format_compact!("/tmp/{}.py", Uuid::new_v4());
+    ///     std::fs::write(&fpath, module_contents.as_bytes()).expect("Failure to write test module.");
+    ///
+    ///     let result: f64 = Python::attach( |py| {
+    ///         let pymod = py_load(py, &fpath).expect("Failure to load module!");
+    ///         let mut args = py_new_args(py);
+    ///         py_push_arg(py, &mut args, &4.0).unwrap();
+    ///         let mut kwargs = RUMPyKwargs::new();
+    ///         py_push_kwarg(py, &mut kwargs, "scale", &2.5).unwrap();
+    ///
+    ///         let result = py_exec_module_kwargs(py, &pymod, "test", &args, &kwargs).unwrap();
+    ///         py_extract_any(py, &result).unwrap()
+    ///     });
+    ///     std::fs::remove_file(&fpath).unwrap();
+    ///
+    ///     assert_eq!(10.0, result, "Bad value returned from Python snippet!")
+    /// ```
+    ///
+    pub fn py_exec_module_kwargs(
+        py: Python,
+        pymod: &RUMPyModule,
+        func_name: &str,
+        args: &RUMPyList,
+        kwargs: &RUMPyKwargs,
+    ) -> RUMResult<RUMPyAny> {
+        if kwargs.is_empty() || func_name.is_empty() {
+            return py_exec_module(py, pymod, func_name, args);
+        }
+        let pykwargs = PyDict::new(py);
+        for (key, value) in kwargs {
+            pykwargs
+                .set_item(key.as_str(), value.bind(py))
+                .map_err(|e| py_error(py, &e))?;
+        }
+        let pyfunc: RUMPyFunction = pymod
+            .getattr(py, func_name)
+            .map_err(|e| py_error(py, &e))
+            .with_context(|| format_compact!("executing Python function {}", func_name))?;
+        pyfunc
+            .call(py, py_list_to_tuple(py, args)?, Some(&pykwargs))
+            .map_err(|e| py_error(py, &e))
+            .with_context(|| format_compact!("executing Python function {}", func_name))
+    }
+
+    ///
+    /// Long-lived handle over a set of named, pre-loaded modules. Load each module once with
+    /// [load_module](RUMPyInterpreter::load_module), then call into it as often as needed. Every
+    /// method acquires the GIL internally.
+    ///
+    /// Instances are not isolated from each other. They all run in the single embedded CPython
+    /// interpreter, so they share `sys.path`, `sys.modules` and global state. See
+    /// [RumtkConfig](crate::config::RumtkConfig).
+    ///
+    /// ## Example
+    ///
+    /// ```
+    ///     use compact_str::format_compact;
+    ///     use pyo3::Python;
+    ///     use crate::rumtk_core::scripting::python_utils::{py_buildargs, RUMPyInterpreter};
+    ///     use crate::rumtk_core::strings::RUMString;
+    ///     use uuid::Uuid;
+    ///
+    ///     let fpath: RUMString = format_compact!("/tmp/{}.py", Uuid::new_v4());
+    ///     std::fs::write(&fpath, b"def test(a, b):\n\treturn a + b").expect("Failure to write test module.");
+    ///
+    ///     let mut interpreter = RUMPyInterpreter::new();
+    ///     interpreter.load_module("math", &fpath).expect("Failure to load module!");
+    ///     std::fs::remove_file(&fpath).unwrap();
+    ///
+    ///     let args = Python::attach(|py| py_buildargs(py, &vec![2, 3]).unwrap());
+    ///     let result: i64 = interpreter.call_extract("math", "test", &args).unwrap();
+    ///
+    ///     assert_eq!(result, 5, "Bad value returned from Python snippet!");
+    /// ```
+    ///
+    #[derive(Default)]
+    pub struct RUMPyInterpreter {
+        modules: RUMCache<RUMString, RUMPyModule>,
+    }
+
+    impl RUMPyInterpreter {
+        pub fn new() -> Self {
+            RUMPyInterpreter::default()
+        }
+
+        ///
+        /// Load the module at `path` with [py_load] and register it as `name`. Loading again under
+        /// the same name replaces the previous module.
+        ///
+        pub fn load_module(&mut self, name: &str, path: &str) -> RUMResult<()> {
+            let module = Python::attach(|py| py_load(py, path))?;
+            self.modules.insert(RUMString::from(name), module);
+            Ok(())
+        }
+
+        pub fn has_module(&self, name: &str) -> bool {
+            self.modules.contains_key(name)
+        }
+
+        ///
+        /// Call `func` in the module registered as `module`. See [py_exec_module].
+        ///
+        pub fn call(&self, module: &str, func: &str, args: &RUMPyList) -> RUMResult<RUMPyAny> {
+            let pymod = self.get_module(module)?;
+            Python::attach(|py| py_exec_module(py, pymod, func, args))
+        }
+
+        ///
+        /// Same as [call](RUMPyInterpreter::call), but extracts the result into `T`.
+        ///
+        pub fn call_extract<T>(&self, module: &str, func: &str, args: &RUMPyList) -> RUMResult<T>
+        where
+            T: for<'py> FromPyObjectOwned<'py>,
+        {
+            let pymod = self.get_module(module)?;
+            Python::attach(|py| {
+                let result = py_exec_module(py, pymod, func, args)?;
+                result.extract::<T>(py).map_err(|e| py_error(py, &e.into()))
+            })
+        }
+
+        fn get_module(&self, name: &str) -> RUMResult<&RUMPyModule> {
+            match self.modules.get(name) {
+                Some(module) => Ok(module),
+                None => Err(RUMError::python(format_compact!(
+                    "Python module {} is not registered with the interpreter!",
+                    name
+                ))),
+            }
+        }
+    }
+
     ///
     /// Runs a closure that follows the signature `|py: RUMPython| -> R {}`.
     /// Remember, the type of the `py` token needs to be explicitly added or there will be a type
@@ -615,6 +1075,42 @@ pub mod python_macros {
     ///
     /// ```
     ///
+    /// ### Running A Function With Keyword Arguments
+    ///
+    /// ```
+    ///     use std::fs::write;
+    ///     use pyo3::Python;
+    ///     use uuid::Uuid;
+    ///     use crate::rumtk_core::core::RUMResult;
+    ///     use crate::rumtk_core::scripting::python_utils::{py_extract_any, py_new_args, py_push_arg, py_push_kwarg, RUMPyKwargs};
+    ///     use crate::rumtk_core::rumtk_python_exec_module;
+    ///
+    ///     fn test_module_exec() -> f64 {
+    ///         let module_fname = format!("{}_module.py", Uuid::new_v4());
+    ///         let module_contents = "def test(a, *, scale=1.0):\n\treturn a*scale";
+    ///         write(&module_fname, module_contents).expect("Failed to write file!");
+    ///
+    ///         let result = Python::attach(|py| -> RUMResult<f64> {
+    ///             let mut args = py_new_args(py);
+    ///             py_push_arg(py, &mut args, &5.0)?;
+    ///             let mut kwargs = RUMPyKwargs::new();
+    ///             py_push_kwarg(py, &mut kwargs, "scale", &2.0)?;
+    ///
+    ///             let result = rumtk_python_exec_module!(py, &module_fname, "test", &args, &kwargs);
+    ///             let val: f64 = py_extract_any(py, &result)?;
+    ///             Ok(val)
+    ///         });
+    ///         std::fs::remove_file(&module_fname).unwrap();
+    ///
+    ///         result.unwrap()
+    ///     }
+    ///
+    ///     let result = test_module_exec();
+    ///
+    ///     assert_eq!(10.0, result, "Bad value returned from Python snippet!")
+    ///
+    /// ```
+    ///
     #[macro_export]
     macro_rules! rumtk_python_exec_module {
         ( $py:expr, $mod_path:expr) => {{
@@ -668,6 +1164,16 @@ pub mod python_macros {
             // Let's execute against arguments
             py_exec_module($py, &pymod, $func_name, $args)?
         }};
+        ( $py:expr, $mod_path:expr, $func_name:expr, $args:expr, $kwargs:expr ) => {{
+            use $crate::scripting::python_utils::RUMPyModule;
+            use $crate::scripting::python_utils::{py_exec_module_kwargs, py_load};
+
+            // Load module
+            let pymod: RUMPyModule = py_load($py, $mod_path)?;
+
+            // Let's execute against positional and keyword arguments
+            py_exec_module_kwargs($py, &pymod, $func_name, $args, $kwargs)?
+        }};
     }
 
     ///
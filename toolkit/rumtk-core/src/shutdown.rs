@@ -0,0 +1,65 @@
+/*
+ * rumtk attempts to implement HL7 and medical protocols for interoperability in medicine.
+ * This toolkit aims to be reliable, simple, performant, and standards compliant.
+ * Copyright (C) 2025  Luis M. Santos, M.D.
+ *
+ * This library is free software; you can redistribute it and/or
+ * modify it under the terms of the GNU Lesser General Public
+ * License as published by the Free Software Foundation; either
+ * version 2.1 of the License, or (at your option) any later version.
+ *
+ * This library is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+ * Lesser General Public License for more details.
+ *
+ * You should have received a copy of the GNU Lesser General Public
+ * License along with this library; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
+ */
+use once_cell::sync::Lazy;
+use std::sync::Mutex;
+
+/**************************** Types *****************************************/
+pub type ShutdownHook = Box<dyn FnOnce() + Send + 'static>;
+
+/**************************** Globals ***************************************/
+static SHUTDOWN_HOOKS: Lazy<Mutex<Vec<ShutdownHook>>> = Lazy::new(|| Mutex::new(Vec::new()));
+
+/**************************** Helpers ***************************************/
+
+///
+/// Register a closure to run when the service shuts down, e.g. to flush a write-back cache or
+/// stop a background thread. See [run_shutdown_hooks].
+///
+pub fn register_shutdown_hook(f: impl FnOnce() + Send + 'static) {
+    SHUTDOWN_HOOKS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .push(Box::new(f));
+}
+
+///
+/// Run every registered shutdown hook, most recently registered first, so components are torn
+/// down in the reverse order they were set up. Call it once when the service is asked to stop
+/// (e.g. on `SIGTERM`).
+///
+/// Each hook runs exactly once. Hooks are removed from the registry before running, so calling
+/// this again only runs hooks registered since the previous call. Hooks may register new hooks;
+/// those wait for the next call.
+///
+/// # Example
+/// ```
+///  use rumtk_core::shutdown::{register_shutdown_hook, run_shutdown_hooks};
+///  register_shutdown_hook(|| println!("Python interpreter finalized!"));
+///  register_shutdown_hook(|| println!("Cache flushed!"));
+///  run_shutdown_hooks();
+///```
+///
+pub fn run_shutdown_hooks() {
+    let hooks: Vec<ShutdownHook> =
+        std::mem::take(&mut *SHUTDOWN_HOOKS.lock().unwrap_or_else(|e| e.into_inner()));
+    for hook in hooks.into_iter().rev() {
+        hook();
+    }
+}
@@ -17,25 +17,157 @@
  * License along with this library; if not, write to the Free Software
  * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
  */
-use crate::core::{is_unique, RUMResult};
+use crate::cache::RUMCache;
+use crate::config::config;
+use crate::core::{is_unique, RUMError, RUMResult};
+use base64::engine::general_purpose::STANDARD as BASE64_ENGINE;
+use base64::Engine;
 use chardetng::EncodingDetector;
 pub use compact_str::{format_compact, CompactString, CompactStringExt, ToCompactString};
 use encoding_rs::Encoding;
+#[cfg(feature = "collation")]
+use icu_collator::{options::CollatorOptions, Collator, CollatorBorrowed};
+#[cfg(feature = "collation")]
+pub use icu_locale::{locale, Locale};
+use std::ffi::{c_char, CStr};
 use std::fmt::Display;
+use std::io::{BufRead, BufReader, Read};
+use std::str::FromStr;
+#[cfg(feature = "unicode-normalization")]
+use unicode_normalization::UnicodeNormalization;
 use unicode_segmentation::UnicodeSegmentation;
 /**************************** Constants**************************************/
 const ESCAPED_STRING_WINDOW: usize = 6;
+const REDACTION_MASK_CHAR: char = '*';
+const ELLIPSIS_STR: &str = "…";
+const JARO_WINKLER_SCALING: f64 = 0.1;
+const JARO_WINKLER_MAX_PREFIX: usize = 4;
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+const NAME_MC_PREFIX: &str = "mc";
+const NAME_PARTICLES: [&str; 15] = [
+    "da", "das", "de", "del", "della", "der", "di", "do", "dos", "du", "la", "le", "van", "von",
+    "y",
+];
+const NAME_SUFFIXES: [&str; 3] = ["II", "III", "IV"];
+const TEMPLATE_OPEN: &str = "{{";
+const TEMPLATE_CLOSE: &str = "}}";
+const TEMPLATE_ESCAPED_OPEN: &str = "\\{\\{";
+const TEMPLATE_ESCAPED_CLOSE: &str = "\\}\\}";
 const ASCII_ESCAPE_CHAR: char = '\\';
 const MIN_ASCII_READABLE: char = ' ';
 const MAX_ASCII_READABLE: char = '~';
 pub const EMPTY_STRING: &str = "";
 pub const DOT_STR: &str = ".";
 pub const EMPTY_STRING_OPTION: Option<&str> = Some("");
+/// HL7 explicit null. A field containing only `""` means the value should be deleted/is null.
+pub const HL7_NULL_STR: &str = "\"\"";
+/// Default record terminator for [read_rumstrings]. HL7 segments end in a carriage return.
+pub const DEFAULT_RECORD_TERMINATOR: u8 = b'\r';
 pub const READABLE_ASCII: &str = " !\"#$%&'()*+,-./0123456789:;<=>?@ABCDEFGHIJKLMNOPQRSTUVWXYZ[\\]^_`abcdefghijklmnopqrstuvwxyz{|}~";
 
 /**************************** Types *****************************************/
 pub type RUMString = CompactString;
 
+///
+/// Borrowed, read only view into a [RUMString] (or any `str`).
+///
+/// Splitting a message into many owned [RUMString] instances is wasteful when we only need to
+/// read the pieces. A [RUMStr] simply points into the parent buffer, so slicing and splitting does
+/// not allocate. Call [RUMStr::to_owned] when an owned copy is actually needed.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct RUMStr<'a> {
+    view: &'a str,
+}
+
+///
+/// Precision of an HL7 `TS`/`DTM` value. Per the standard, the number of characters populated
+/// (excluding the time zone) specifies the precision. `Fraction` carries the number of fractional
+/// second digits (1 to 4).
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Hl7TimestampPrecision {
+    Year,
+    Month,
+    Day,
+    Hour,
+    Minute,
+    Second,
+    Fraction(u8),
+}
+
+///
+/// Structured representation of an HL7 `TS`/`DTM` value.
+///
+/// ## Format:
+/// ```text
+///     YYYY[MM[DD[HH[MM[SS[.S[S[S[S]]]]]]]]][+/-ZZZZ]
+/// ```
+///
+/// Fields beyond the parsed precision keep their lowest valid value (month and day are 1, the rest
+/// are 0). The `fraction` is stored in ten thousandths of a second and the time zone `offset` in
+/// minutes from UTC.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Hl7Timestamp {
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+    pub fraction: u16,
+    pub offset: Option<i16>,
+    pub precision: Hl7TimestampPrecision,
+}
+
+///
+/// Policy deciding how much of a value [redact] leaves visible. Only letters and digits are ever
+/// masked, so separators (like HL7 delimiters) keep the structure of the value readable.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RedactionPolicy {
+    /// Mask every letter and digit.
+    #[default]
+    Full,
+    /// Keep the first and last character of each word. Words of 2 characters or fewer are fully
+    /// masked since there would be nothing left to hide.
+    KeepFirstLast,
+    /// Keep the last `n` letters or digits of the whole value (e.g. last 4 of an identifier).
+    KeepLast(usize),
+}
+
+///
+/// Unicode normalization forms supported by [normalize_unicode].
+///
+#[cfg(feature = "unicode-normalization")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NormalizationForm {
+    /// Canonical composition. `e` followed by a combining acute accent becomes `é`.
+    NFC,
+    /// Canonical decomposition. `é` becomes `e` followed by a combining acute accent.
+    NFD,
+}
+
+///
+/// Append only string builder for very large messages (e.g. batch files).
+///
+/// [RUMRope::push] stores each piece as its own chunk. Its cost is amortized O(1) in the size of
+/// the rope: the chunk list grows geometrically, and only the new piece is copied. Pieces of up
+/// to 24 bytes are stored inline, without a heap allocation of their own. Rebuilding a growing
+/// [RUMString] on each append instead copies everything written so far, which is quadratic over
+/// the whole message.
+///
+/// [RUMRope::collapse] makes a single allocation of the exact final size and copies every chunk
+/// into it once.
+///
+#[derive(Debug, Clone, Default)]
+pub struct RUMRope {
+    chunks: Vec<RUMString>,
+    len: usize,
+}
+
 /**************************** Traits ****************************************/
 
 ///
@@ -149,6 +281,31 @@ pub trait StringUtils: AsStr + UTFStringExtensions {
         let graphemes = self.get_graphemes();
         is_unique(&graphemes)
     }
+
+    ///
+    /// ASCII case-insensitive version of `starts_with`. No allocation takes place.
+    ///
+    /// # Example
+    /// ```
+    ///  use rumtk_core::strings::{RUMString, StringUtils};
+    ///  assert!(RUMString::from("msh|^~\\&").starts_with_ignore_case("MSH"));
+    ///```
+    ///
+    #[inline(always)]
+    fn starts_with_ignore_case(&self, prefix: &str) -> bool {
+        let bytes = self.as_str().as_bytes();
+        bytes.len() >= prefix.len() && bytes[..prefix.len()].eq_ignore_ascii_case(prefix.as_bytes())
+    }
+
+    ///
+    /// ASCII case-insensitive version of `ends_with`. No allocation takes place.
+    ///
+    #[inline(always)]
+    fn ends_with_ignore_case(&self, suffix: &str) -> bool {
+        let bytes = self.as_str().as_bytes();
+        bytes.len() >= suffix.len()
+            && bytes[bytes.len() - suffix.len()..].eq_ignore_ascii_case(suffix.as_bytes())
+    }
 }
 
 impl UTFStringExtensions for RUMString {
@@ -237,6 +394,157 @@ impl RUMArrayConversions for &[u8] {
     }
 }
 
+impl<'a> RUMStr<'a> {
+    pub fn new(view: &'a str) -> RUMStr<'a> {
+        RUMStr { view }
+    }
+
+    pub fn len(&self) -> usize {
+        self.view.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.view.is_empty()
+    }
+
+    pub fn starts_with(&self, prefix: &str) -> bool {
+        self.view.starts_with(prefix)
+    }
+
+    pub fn ends_with(&self, suffix: &str) -> bool {
+        self.view.ends_with(suffix)
+    }
+
+    pub fn contains(&self, pattern: &str) -> bool {
+        self.view.contains(pattern)
+    }
+
+    ///
+    /// Split the view on `delimiter`. Each item is itself a [RUMStr] borrowing from the same
+    /// parent buffer, so no substring gets copied.
+    ///
+    pub fn split(&self, delimiter: &'a str) -> impl Iterator<Item = RUMStr<'a>> + 'a {
+        self.view.split(delimiter).map(RUMStr::new)
+    }
+
+    ///
+    /// Return the `index`th (0-indexed) piece of the view split on `delimiter`.
+    ///
+    pub fn split_nth(&self, delimiter: &'a str, index: usize) -> Option<RUMStr<'a>> {
+        self.split(delimiter).nth(index)
+    }
+
+    pub fn trim(&self) -> RUMStr<'a> {
+        RUMStr::new(self.view.trim())
+    }
+
+    ///
+    /// Escape hatch for when an owned [RUMString] is needed. This is the only operation in this
+    /// type that allocates.
+    ///
+    pub fn to_owned(&self) -> RUMString {
+        RUMString::from(self.view)
+    }
+}
+
+impl<'a> AsStr for RUMStr<'a> {
+    fn as_str(&self) -> &str {
+        self.view
+    }
+}
+
+impl<'a> From<&'a RUMString> for RUMStr<'a> {
+    fn from(value: &'a RUMString) -> Self {
+        RUMStr::new(value.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for RUMStr<'a> {
+    fn from(value: &'a str) -> Self {
+        RUMStr::new(value)
+    }
+}
+
+impl PartialEq<str> for RUMStr<'_> {
+    fn eq(&self, other: &str) -> bool {
+        self.view == other
+    }
+}
+
+impl PartialEq<&str> for RUMStr<'_> {
+    fn eq(&self, other: &&str) -> bool {
+        self.view == *other
+    }
+}
+
+impl Display for RUMStr<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.view)
+    }
+}
+
+impl RUMRope {
+    pub fn new() -> RUMRope {
+        RUMRope::default()
+    }
+
+    ///
+    /// Append a piece to the end of the rope. Empty pieces are ignored. Only `piece` is copied, so
+    /// the cost does not depend on how much the rope already holds.
+    ///
+    pub fn push(&mut self, piece: &str) {
+        if piece.is_empty() {
+            return;
+        }
+        self.len += piece.len();
+        self.chunks.push(RUMString::from(piece));
+    }
+
+    ///
+    /// Length in bytes of the string [RUMRope::collapse] would produce.
+    ///
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn chunk_count(&self) -> usize {
+        self.chunks.len()
+    }
+
+    ///
+    /// Materialize the rope into a single [RUMString], allocated once with the exact final size.
+    ///
+    /// # Example
+    /// ```
+    ///  use rumtk_core::strings::RUMRope;
+    ///  let mut rope = RUMRope::new();
+    ///  rope.push("MSH|^~\\&|ADT\r");
+    ///  rope.push("PID|1||12345\r");
+    ///  assert_eq!("MSH|^~\\&|ADT\rPID|1||12345\r", rope.collapse());
+    ///```
+    ///
+    pub fn collapse(&self) -> RUMString {
+        let mut collapsed = RUMString::with_capacity(self.len);
+        for chunk in self.chunks.iter() {
+            collapsed.push_str(chunk);
+        }
+        collapsed
+    }
+}
+
+impl Display for RUMRope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for chunk in self.chunks.iter() {
+            f.write_str(chunk)?;
+        }
+        Ok(())
+    }
+}
+
 /**************************** Helpers ***************************************/
 
 pub fn count_tokens_ignoring_pattern(vector: &Vec<&str>, string_token: &RUMString) -> usize {
@@ -364,7 +672,7 @@ pub fn get_grapheme_collection<'a>(
 /// We can then use the bytes and attempt a decode() to figure out the string encoding and
 /// get the correct conversion to UTF-8. **Fingers crossed**
 ///
-pub fn unescape(escaped_str: &str) -> Result<Vec<u8>, RUMString> {
+pub fn unescape(escaped_str: &str) -> RUMResult<Vec<u8>> {
     let lower_case = escaped_str.to_lowercase();
     let mut bytes: Vec<u8> = Vec::with_capacity(3);
     match &lower_case[0..2] {
@@ -400,10 +708,10 @@ pub fn unescape(escaped_str: &str) -> Result<Vec<u8>, RUMString> {
                 bytes.push(hex_to_byte(&lower_case[4..6])?);
             }
             _ => {
-                return Err(format_compact!(
+                return Err(RUMError::parse(format_compact!(
                     "Unknown multibyte sequence. Cannot decode {}",
                     lower_case
-                ))
+                )))
             }
         },
         // Custom encoding
@@ -418,7 +726,7 @@ pub fn unescape(escaped_str: &str) -> Result<Vec<u8>, RUMString> {
 /// Unescape basic character
 /// We use pattern matching to map the basic escape character to its corresponding integer value.
 ///
-fn unescape_control(escaped_str: &str) -> Result<char, RUMString> {
+fn unescape_control(escaped_str: &str) -> RUMResult<char> {
     match escaped_str {
         // Common control sequences
         "\\t" => Ok('\t'),
@@ -434,10 +742,10 @@ fn unescape_control(escaped_str: &str) -> Result<char, RUMString> {
         "\\v" => Ok('\x0B'),
         "\\a" => Ok('\x07'),
         // Control sequences by
-        _ => Err(format_compact!(
+        _ => Err(RUMError::parse(format_compact!(
             "Unknown escape sequence? Sequence: {}!",
             escaped_str
-        )),
+        ))),
     }
 }
 
@@ -445,7 +753,7 @@ fn unescape_control(escaped_str: &str) -> Result<char, RUMString> {
 /// Unescape basic character
 /// We use pattern matching to map the basic escape character to its corresponding integer value.
 ///
-fn unescape_control_byte(escaped_str: &str) -> Result<u8, RUMString> {
+fn unescape_control_byte(escaped_str: &str) -> RUMResult<u8> {
     match escaped_str {
         // Common control sequences
         "\\t" => Ok(9),   // Tab/Character Tabulation
@@ -461,7 +769,7 @@ fn unescape_control_byte(escaped_str: &str) -> Result<u8, RUMString> {
         "\\v" => Ok(11),  // Vertical Tab/Line Tabulation
         "\\a" => Ok(7),   // Alert bell
         // Control sequences by hex
-        //Err(format_compact!("Unknown escape sequence? Sequence: {}!", escaped_str))
+        //Err(RUMError::parse(format_compact!("Unknown escape sequence? Sequence: {}!", escaped_str)))
         _ => hex_to_byte(escaped_str),
     }
 }
@@ -469,73 +777,73 @@ fn unescape_control_byte(escaped_str: &str) -> Result<u8, RUMString> {
 ///
 /// Turn hex string to number (u32)
 ///
-fn hex_to_number(hex_str: &str) -> Result<u32, RUMString> {
+fn hex_to_number(hex_str: &str) -> RUMResult<u32> {
     match u32::from_str_radix(&hex_str, 16) {
         Ok(result) => Ok(result),
-        Err(val) => Err(format_compact!(
+        Err(val) => Err(RUMError::parse(format_compact!(
             "Failed to parse string with error {}! Input string {} \
         is not hex string!",
             val,
             hex_str
-        )),
+        ))),
     }
 }
 
 ///
 /// Turn hex string to byte (u8)
 ///
-fn hex_to_byte(hex_str: &str) -> Result<u8, RUMString> {
+fn hex_to_byte(hex_str: &str) -> RUMResult<u8> {
     match u8::from_str_radix(&hex_str, 16) {
         Ok(result) => Ok(result),
-        Err(val) => Err(format_compact!(
+        Err(val) => Err(RUMError::parse(format_compact!(
             "Failed to parse string with error {}! Input string {} \
         is not hex string!",
             val,
             hex_str
-        )),
+        ))),
     }
 }
 
 ///
 /// Turn octal string to number (u32)
 ///
-fn octal_to_number(hoctal_str: &str) -> Result<u32, RUMString> {
+fn octal_to_number(hoctal_str: &str) -> RUMResult<u32> {
     match u32::from_str_radix(&hoctal_str, 8) {
         Ok(result) => Ok(result),
-        Err(val) => Err(format_compact!(
+        Err(val) => Err(RUMError::parse(format_compact!(
             "Failed to parse string with error {}! Input string {} \
         is not an octal string!",
             val,
             hoctal_str
-        )),
+        ))),
     }
 }
 
 ///
 /// Turn octal string to byte (u32)
 ///
-fn octal_to_byte(hoctal_str: &str) -> Result<u8, RUMString> {
+fn octal_to_byte(hoctal_str: &str) -> RUMResult<u8> {
     match u8::from_str_radix(&hoctal_str, 8) {
         Ok(result) => Ok(result),
-        Err(val) => Err(format_compact!(
+        Err(val) => Err(RUMError::parse(format_compact!(
             "Failed to parse string with error {}! Input string {} \
         is not an octal string!",
             val,
             hoctal_str
-        )),
+        ))),
     }
 }
 
 ///
 /// Turn number to UTF-8 char
 ///
-fn number_to_char(num: &u32) -> Result<RUMString, RUMString> {
+fn number_to_char(num: &u32) -> RUMResult<RUMString> {
     match char::from_u32(*num) {
         Some(result) => Ok(result.to_rumstring()),
-        None => Err(format_compact!(
+        None => Err(RUMError::parse(format_compact!(
             "Failed to cast number to character! Number {}",
             num
-        )),
+        ))),
     }
 }
 
@@ -637,3 +945,1396 @@ pub fn filter_ascii(unescaped_str: &str, closure: fn(char) -> bool) -> RUMString
 pub fn filter_non_printable_ascii(unescaped_str: &str) -> RUMString {
     filter_ascii(unescaped_str, |c: char| is_printable_char(&c))
 }
+
+///
+/// Removes leading and trailing ASCII control characters from a string.
+///
+/// Inbound messages sometimes carry stray bytes like `NUL` or the MLLP framing characters
+/// (`\x0b`, `\x1c`) around the actual payload. This is a shortcut for
+/// [trim_control_chars_with] with the default options.
+///
+/// # Example
+/// ```
+///  use rumtk_core::strings::trim_control_chars;
+///  let trimmed = trim_control_chars("\x0bMSH|^~\\&|ADT\x1c\r");
+///  assert_eq!("MSH|^~\\&|ADT", &trimmed, "Did not get expected trimmed string! Got {}!", &trimmed);
+///```
+///
+pub fn trim_control_chars(s: &str) -> RUMString {
+    trim_control_chars_with(s, false, false)
+}
+
+///
+/// Removes ASCII control characters from a string.
+///
+/// * If `strip_all` is false, only the leading and trailing control characters are removed.
+///   Otherwise, every control character in the string is removed.
+/// * If `keep_cr` is true, `\r` segment terminators are preserved. When trimming the edges, only
+///   the `\r` immediately following the payload is kept, so a trailing `\x1c\r` MLLP frame end is
+///   dropped without losing the last segment terminator.
+///
+pub fn trim_control_chars_with(s: &str, strip_all: bool, keep_cr: bool) -> RUMString {
+    let is_trimmable = |c: char| c.is_ascii_control();
+    let start = match s.find(|c: char| !is_trimmable(c)) {
+        Some(i) => i,
+        None => return RUMString::from(EMPTY_STRING),
+    };
+    let content_end = match s.rfind(|c: char| !is_trimmable(c)) {
+        Some(i) => i + s[i..].chars().next().map_or(1, |c| c.len_utf8()),
+        None => return RUMString::from(EMPTY_STRING),
+    };
+    let end = match keep_cr && s[content_end..].starts_with('\r') {
+        true => content_end + 1,
+        false => content_end,
+    };
+    let trimmed = &s[start..end];
+
+    if !strip_all {
+        return RUMString::from(trimmed);
+    }
+
+    let mut filtered = RUMString::with_capacity(trimmed.len());
+    for c in trimmed.chars() {
+        if !c.is_ascii_control() || (keep_cr && c == '\r') {
+            filtered.push(c);
+        }
+    }
+    filtered
+}
+
+///
+/// Encode a byte slice into a standard (padded) base64 string.
+///
+/// Useful for HL7 encapsulated data (the `ED` data type) where binary payloads travel in text.
+///
+/// # Example
+/// ```
+///  use rumtk_core::strings::base64_encode;
+///  let encoded = base64_encode("Hello World!".as_bytes());
+///  assert_eq!("SGVsbG8gV29ybGQh", &encoded, "Did not get expected base64 string! Got {}!", &encoded);
+///```
+///
+pub fn base64_encode(bytes: &[u8]) -> RUMString {
+    RUMString::from(BASE64_ENGINE.encode(bytes))
+}
+
+///
+/// Decode a standard (padded) base64 string back into its raw bytes.
+///
+/// Invalid base64 input results in an error describing why decoding failed.
+///
+/// # Example
+/// ```
+///  use rumtk_core::strings::{base64_decode, RUMString};
+///  let decoded = base64_decode(&RUMString::from("SGVsbG8gV29ybGQh")).unwrap();
+///  assert_eq!("Hello World!".as_bytes(), decoded.as_slice(), "Did not get expected bytes!");
+///```
+///
+pub fn base64_decode(s: &str) -> RUMResult<Vec<u8>> {
+    match BASE64_ENGINE.decode(s) {
+        Ok(bytes) => Ok(bytes),
+        Err(e) => Err(RUMError::parse(format_compact!(
+            "Failed to decode base64 string because {}! Input string {} is not valid base64!",
+            e,
+            s
+        ))),
+    }
+}
+
+///
+/// Encode a byte slice as a lowercase hex string with no separators.
+///
+/// # Example
+/// ```
+///  use rumtk_core::strings::hex_encode;
+///  let encoded = hex_encode(&[0x0b, 0x1c, 0xff]);
+///  assert_eq!("0b1cff", &encoded, "Did not get expected hex string! Got {}!", &encoded);
+///```
+///
+pub fn hex_encode(bytes: &[u8]) -> RUMString {
+    let mut encoded = RUMString::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        encoded.push_str(&format_compact!("{:02x}", byte));
+    }
+    encoded
+}
+
+///
+/// Decode a hex string (upper or lower case, no separators) into its raw bytes.
+///
+/// Odd length strings and strings containing non hex characters result in an error.
+///
+/// # Example
+/// ```
+///  use rumtk_core::strings::{hex_decode, RUMString};
+///  let decoded = hex_decode(&RUMString::from("0B1cFF")).unwrap();
+///  assert_eq!(vec![0x0b, 0x1c, 0xff], decoded, "Did not get expected bytes!");
+///```
+///
+pub fn hex_decode(s: &str) -> RUMResult<Vec<u8>> {
+    if s.len() % 2 != 0 {
+        return Err(RUMError::parse(format_compact!(
+            "Hex string {} has an odd length of {}! Expected pairs of hex digits.",
+            s,
+            s.len()
+        )));
+    }
+    if !s.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(RUMError::parse(format_compact!(
+            "Input string {} is not hex string! Found non hex characters.",
+            s
+        )));
+    }
+    let mut bytes: Vec<u8> = Vec::with_capacity(s.len() / 2);
+    for i in (0..s.len()).step_by(2) {
+        bytes.push(hex_to_byte(&s[i..i + 2])?);
+    }
+    Ok(bytes)
+}
+
+///
+/// Join a collection of strings with a separator.
+///
+/// The output buffer is allocated once with the exact capacity needed, so no reallocation happens
+/// while writing the parts.
+///
+/// # Example
+/// ```
+///  use rumtk_core::strings::{rum_join, RUMString};
+///  let parts = vec![RUMString::from("PID"), RUMString::from("1"), RUMString::from("")];
+///  let joined = rum_join(&parts, "|");
+///  assert_eq!("PID|1|", &joined, "Did not get expected joined string! Got {}!", &joined);
+///```
+///
+pub fn rum_join(parts: &[RUMString], sep: &str) -> RUMString {
+    if parts.is_empty() {
+        return RUMString::from(EMPTY_STRING);
+    }
+    let capacity =
+        parts.iter().map(|part| part.len()).sum::<usize>() + sep.len() * (parts.len() - 1);
+    let mut joined = RUMString::with_capacity(capacity);
+    for (i, part) in parts.iter().enumerate() {
+        if i > 0 {
+            joined.push_str(sep);
+        }
+        joined.push_str(part);
+    }
+    joined
+}
+
+///
+/// Convenience wrapper around [rum_join] for HL7 delimiters, which are always single characters
+/// (`|` for fields, `^` for components, etc).
+///
+pub fn join_hl7(parts: &[RUMString], delimiter_char: char) -> RUMString {
+    let mut buffer = [0u8; 4];
+    rum_join(parts, delimiter_char.encode_utf8(&mut buffer))
+}
+
+///
+/// Parse an HL7 `TS`/`DTM` value like `20240115123045.123-0500` into a [Hl7Timestamp].
+///
+/// Any precision from year only up to ten thousandths of a second is accepted, with an optional
+/// `+/-HHMM` time zone. Malformed or out of range values return an error.
+///
+/// # Example
+/// ```
+///  use rumtk_core::strings::{parse_hl7_ts, Hl7TimestampPrecision, RUMString};
+///  let ts = parse_hl7_ts(&RUMString::from("202401151230-0500")).unwrap();
+///  assert_eq!(ts.hour, 12);
+///  assert_eq!(ts.offset, Some(-300));
+///  assert_eq!(ts.precision, Hl7TimestampPrecision::Minute);
+///```
+///
+pub fn parse_hl7_ts(s: &str) -> RUMResult<Hl7Timestamp> {
+    let input = s.trim();
+    let (datetime, offset) = match input.find(['+', '-']) {
+        Some(i) => (&input[..i], Some(parse_hl7_ts_offset(&input[i..])?)),
+        None => (input, None),
+    };
+    let (digits, fraction_digits) = match datetime.split_once('.') {
+        Some((digits, fraction)) => (digits, Some(fraction)),
+        None => (datetime, None),
+    };
+
+    if !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(RUMError::parse(format_compact!(
+            "Invalid HL7 timestamp {}! Expected only digits before the fraction and time zone.",
+            s
+        )));
+    }
+
+    let mut ts = Hl7Timestamp {
+        year: 0,
+        month: 1,
+        day: 1,
+        hour: 0,
+        minute: 0,
+        second: 0,
+        fraction: 0,
+        offset,
+        precision: Hl7TimestampPrecision::Year,
+    };
+    ts.precision = match digits.len() {
+        4 => Hl7TimestampPrecision::Year,
+        6 => Hl7TimestampPrecision::Month,
+        8 => Hl7TimestampPrecision::Day,
+        10 => Hl7TimestampPrecision::Hour,
+        12 => Hl7TimestampPrecision::Minute,
+        14 => Hl7TimestampPrecision::Second,
+        _ => {
+            return Err(RUMError::parse(format_compact!(
+                "Invalid HL7 timestamp {}! Expected 4, 6, 8, 10, 12, or 14 digits but got {}.",
+                s,
+                digits.len()
+            )))
+        }
+    };
+
+    ts.year = parse_ts_digits(digits, 0, 4, 0, 9999, s)? as u16;
+    ts.month = parse_ts_digits(digits, 4, 2, 1, 12, s)? as u8;
+    ts.day = parse_ts_digits(digits, 6, 2, 1, days_in_month(ts.year, ts.month), s)? as u8;
+    ts.hour = parse_ts_digits(digits, 8, 2, 0, 23, s)? as u8;
+    ts.minute = parse_ts_digits(digits, 10, 2, 0, 59, s)? as u8;
+    ts.second = parse_ts_digits(digits, 12, 2, 0, 59, s)? as u8;
+
+    if let Some(fraction) = fraction_digits {
+        if ts.precision != Hl7TimestampPrecision::Second
+            || fraction.is_empty()
+            || fraction.len() > 4
+            || !fraction.bytes().all(|b| b.is_ascii_digit())
+        {
+            return Err(RUMError::parse(format_compact!(
+                "Invalid HL7 timestamp {}! Fractional seconds require a full timestamp and 1 to 4 digits.",
+                s
+            )));
+        }
+        let padded = format_compact!("{:0<4}", fraction);
+        ts.fraction = parse_ts_digits(&padded, 0, 4, 0, 9999, s)? as u16;
+        ts.precision = Hl7TimestampPrecision::Fraction(fraction.len() as u8);
+    }
+
+    Ok(ts)
+}
+
+///
+/// Format a [Hl7Timestamp] back into its HL7 `TS`/`DTM` string representation, truncated to the
+/// requested `precision`. The time zone is appended if the timestamp has one.
+///
+/// # Example
+/// ```
+///  use rumtk_core::strings::{format_hl7_ts, parse_hl7_ts, Hl7TimestampPrecision, RUMString};
+///  let ts = parse_hl7_ts(&RUMString::from("20240115123045.123-0500")).unwrap();
+///  assert_eq!(format_hl7_ts(&ts, ts.precision), "20240115123045.123-0500");
+///  assert_eq!(format_hl7_ts(&ts, Hl7TimestampPrecision::Day), "20240115-0500");
+///```
+///
+pub fn format_hl7_ts(ts: &Hl7Timestamp, precision: Hl7TimestampPrecision) -> RUMString {
+    let full = format_compact!(
+        "{:04}{:02}{:02}{:02}{:02}{:02}",
+        ts.year,
+        ts.month,
+        ts.day,
+        ts.hour,
+        ts.minute,
+        ts.second
+    );
+    let mut formatted = match precision {
+        Hl7TimestampPrecision::Year => RUMString::from(&full[..4]),
+        Hl7TimestampPrecision::Month => RUMString::from(&full[..6]),
+        Hl7TimestampPrecision::Day => RUMString::from(&full[..8]),
+        Hl7TimestampPrecision::Hour => RUMString::from(&full[..10]),
+        Hl7TimestampPrecision::Minute => RUMString::from(&full[..12]),
+        Hl7TimestampPrecision::Second => full,
+        Hl7TimestampPrecision::Fraction(digits) => {
+            let fraction = format_compact!("{:04}", ts.fraction);
+            let digits = (digits as usize).clamp(1, 4);
+            format_compact!("{}.{}", full, &fraction[..digits])
+        }
+    };
+    if let Some(offset) = ts.offset {
+        let sign = if offset < 0 { '-' } else { '+' };
+        let minutes = offset.unsigned_abs();
+        formatted.push_str(&format_compact!(
+            "{}{:02}{:02}",
+            sign,
+            minutes / 60,
+            minutes % 60
+        ));
+    }
+    formatted
+}
+
+///
+/// Parse a `+/-HHMM` time zone into a number of minutes from UTC.
+///
+fn parse_hl7_ts_offset(offset: &str) -> RUMResult<i16> {
+    let digits = &offset[1..];
+    if digits.len() != 4 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(RUMError::parse(format_compact!(
+            "Invalid HL7 time zone {}! Expected the +/-HHMM format.",
+            offset
+        )));
+    }
+    let hours = parse_ts_digits(digits, 0, 2, 0, 14, offset)? as i16;
+    let minutes = parse_ts_digits(digits, 2, 2, 0, 59, offset)? as i16;
+    let total = hours * 60 + minutes;
+    match offset.starts_with('-') {
+        true => Ok(-total),
+        false => Ok(total),
+    }
+}
+
+///
+/// Parse the `width` digits found at `start` and validate the value is within `min..=max`.
+/// Missing digits (lower precision timestamp) yield `min`.
+///
+fn parse_ts_digits(
+    digits: &str,
+    start: usize,
+    width: usize,
+    min: u32,
+    max: u32,
+    original: &str,
+) -> RUMResult<u32> {
+    if digits.len() < start + width {
+        return Ok(min);
+    }
+    let value = match digits[start..start + width].parse::<u32>() {
+        Ok(value) => value,
+        Err(e) => {
+            return Err(RUMError::parse(format_compact!(
+                "Failed to parse HL7 timestamp {} because {}!",
+                original,
+                e
+            )))
+        }
+    };
+    if value < min || value > max {
+        return Err(RUMError::parse(format_compact!(
+            "Invalid HL7 timestamp {}! Value {} is outside the {} <= x <= {} range.",
+            original,
+            value,
+            min,
+            max
+        )));
+    }
+    Ok(value)
+}
+
+fn days_in_month(year: u16, month: u8) -> u32 {
+    match month {
+        4 | 6 | 9 | 11 => 30,
+        2 if (year % 4 == 0 && year % 100 != 0) || year % 400 == 0 => 29,
+        2 => 28,
+        _ => 31,
+    }
+}
+
+///
+/// Parse a numeric value (any of the primitive integer or float types) out of a string, ignoring
+/// surrounding whitespace.
+///
+/// Empty fields, the HL7 explicit null (`""`), and non numeric input are errors. Use
+/// [parse_number_opt] if missing values are acceptable.
+///
+/// # Example
+/// ```
+///  use rumtk_core::strings::{parse_number, RUMString};
+///  let value: i64 = parse_number(&RUMString::from(" 42 ")).unwrap();
+///  assert_eq!(42, value);
+///  let value: f64 = parse_number(&RUMString::from("98.6")).unwrap();
+///  assert_eq!(98.6, value);
+///```
+///
+pub fn parse_number<T>(s: &str) -> RUMResult<T>
+where
+    T: FromStr,
+    <T as FromStr>::Err: Display,
+{
+    match parse_number_opt(s)? {
+        Some(value) => Ok(value),
+        None => Err(RUMError::parse(format_compact!(
+            "Expected a number but the field {:?} is empty or null!",
+            s
+        ))),
+    }
+}
+
+///
+/// Same as [parse_number], but empty fields and the HL7 explicit null (`""`) yield `Ok(None)`
+/// instead of an error. Non numeric input is still an error.
+///
+pub fn parse_number_opt<T>(s: &str) -> RUMResult<Option<T>>
+where
+    T: FromStr,
+    <T as FromStr>::Err: Display,
+{
+    let trimmed = s.trim();
+    if trimmed.is_empty() || trimmed == HL7_NULL_STR {
+        return Ok(None);
+    }
+    match trimmed.parse::<T>() {
+        Ok(value) => Ok(Some(value)),
+        Err(e) => Err(RUMError::parse(format_compact!(
+            "Failed to parse number from {:?} because {}!",
+            s,
+            e
+        ))),
+    }
+}
+
+///
+/// Replace `{{name}}` placeholders in a template with the matching values in `vars`.
+///
+/// A placeholder without a matching key is an error. See [render_template_with] to leave missing
+/// placeholders untouched instead. Write `\{\{` (or `\}\}`) to emit a literal `{{` (or `}}`).
+///
+/// # Example
+/// ```
+///  use rumtk_core::cache::RUMCache;
+///  use rumtk_core::strings::{render_template, RUMString};
+///  let mut vars = RUMCache::<RUMString, RUMString>::default();
+///  vars.insert(RUMString::from("mrn"), RUMString::from("12345"));
+///  let rendered = render_template(&RUMString::from("PID|1||{{mrn}}"), &vars).unwrap();
+///  assert_eq!("PID|1||12345", &rendered, "Did not get expected rendered string! Got {}!", &rendered);
+///```
+///
+pub fn render_template(
+    template: &str,
+    vars: &RUMCache<RUMString, RUMString>,
+) -> RUMResult<RUMString> {
+    render_template_with(template, vars, false)
+}
+
+///
+/// Same as [render_template], but if `keep_missing` is true, placeholders whose key is not in
+/// `vars` are left in the output as is instead of producing an error.
+///
+pub fn render_template_with(
+    template: &str,
+    vars: &RUMCache<RUMString, RUMString>,
+    keep_missing: bool,
+) -> RUMResult<RUMString> {
+    let mut rendered = RUMString::with_capacity(template.len());
+    let mut remaining = template;
+    while !remaining.is_empty() {
+        if let Some(rest) = remaining.strip_prefix(TEMPLATE_ESCAPED_OPEN) {
+            rendered.push_str(TEMPLATE_OPEN);
+            remaining = rest;
+        } else if let Some(rest) = remaining.strip_prefix(TEMPLATE_ESCAPED_CLOSE) {
+            rendered.push_str(TEMPLATE_CLOSE);
+            remaining = rest;
+        } else if let Some(rest) = remaining.strip_prefix(TEMPLATE_OPEN) {
+            let end = match rest.find(TEMPLATE_CLOSE) {
+                Some(end) => end,
+                None => {
+                    return Err(RUMError::parse(format_compact!(
+                        "Unterminated placeholder in template {:?}! Missing closing {}.",
+                        template,
+                        TEMPLATE_CLOSE
+                    )))
+                }
+            };
+            let key = rest[..end].trim();
+            match vars.get(key) {
+                Some(value) => rendered.push_str(value),
+                None if keep_missing => rendered
+                    .push_str(&remaining[..end + TEMPLATE_OPEN.len() + TEMPLATE_CLOSE.len()]),
+                None => {
+                    return Err(RUMError::parse(format_compact!(
+                        "Missing value for placeholder {} in template!",
+                        key
+                    )))
+                }
+            }
+            remaining = &rest[end + TEMPLATE_CLOSE.len()..];
+        } else {
+            let c = remaining.chars().next().unwrap();
+            rendered.push(c);
+            remaining = &remaining[c.len_utf8()..];
+        }
+    }
+    Ok(rendered)
+}
+
+///
+/// Compute the Levenshtein edit distance (insertions, deletions, and substitutions) between two
+/// strings, counted in Unicode scalar values.
+///
+/// Only two rows of the dynamic programming matrix are kept in memory at a time.
+///
+/// # Example
+/// ```
+///  use rumtk_core::strings::levenshtein;
+///  assert_eq!(3, levenshtein("kitten", "sitting"));
+///```
+///
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    let a_chars: Vec<char> = a.chars().collect();
+    let b_chars: Vec<char> = b.chars().collect();
+    if a_chars.is_empty() {
+        return b_chars.len();
+    }
+    if b_chars.is_empty() {
+        return a_chars.len();
+    }
+
+    let mut previous: Vec<usize> = (0..=b_chars.len()).collect();
+    let mut current: Vec<usize> = vec![0; b_chars.len() + 1];
+    for (i, a_char) in a_chars.iter().enumerate() {
+        current[0] = i + 1;
+        for (j, b_char) in b_chars.iter().enumerate() {
+            let substitution_cost = if a_char == b_char { 0 } else { 1 };
+            current[j + 1] = (previous[j + 1] + 1)
+                .min(current[j] + 1)
+                .min(previous[j] + substitution_cost);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous[b_chars.len()]
+}
+
+///
+/// Normalized [levenshtein] similarity between two strings in the `0.0..=1.0` range, where `1.0`
+/// means the strings are identical.
+///
+pub fn similarity(a: &str, b: &str) -> f64 {
+    let max_len = a.chars().count().max(b.chars().count());
+    if max_len == 0 {
+        return 1.0;
+    }
+    1.0 - (levenshtein(a, b) as f64 / max_len as f64)
+}
+
+///
+/// Compute the Jaro-Winkler similarity between two strings in the `0.0..=1.0` range, where `1.0`
+/// means the strings are identical. Matching prefixes (up to 4 characters) are rewarded, which
+/// tends to rank short names and typos better than [similarity].
+///
+/// # Example
+/// ```
+///  use rumtk_core::strings::jaro_winkler;
+///  assert!((jaro_winkler("MARTHA", "MARHTA") - 0.961).abs() < 0.001);
+///```
+///
+pub fn jaro_winkler(a: &str, b: &str) -> f64 {
+    let jaro_score = jaro(a, b);
+    let prefix_len = a
+        .chars()
+        .zip(b.chars())
+        .take(JARO_WINKLER_MAX_PREFIX)
+        .take_while(|(a_char, b_char)| a_char == b_char)
+        .count();
+    jaro_score + prefix_len as f64 * JARO_WINKLER_SCALING * (1.0 - jaro_score)
+}
+
+fn jaro(a: &str, b: &str) -> f64 {
+    let a_chars: Vec<char> = a.chars().collect();
+    let b_chars: Vec<char> = b.chars().collect();
+    if a_chars.is_empty() && b_chars.is_empty() {
+        return 1.0;
+    }
+    if a_chars.is_empty() || b_chars.is_empty() {
+        return 0.0;
+    }
+
+    let window = (a_chars.len().max(b_chars.len()) / 2).saturating_sub(1);
+    let mut a_matched = vec![false; a_chars.len()];
+    let mut b_matched = vec![false; b_chars.len()];
+    let mut matches = 0;
+    for (i, a_char) in a_chars.iter().enumerate() {
+        let start = i.saturating_sub(window);
+        let end = (i + window + 1).min(b_chars.len());
+        for j in start..end {
+            if !b_matched[j] && b_chars[j] == *a_char {
+                a_matched[i] = true;
+                b_matched[j] = true;
+                matches += 1;
+                break;
+            }
+        }
+    }
+    if matches == 0 {
+        return 0.0;
+    }
+
+    let a_sequence = a_chars.iter().zip(&a_matched).filter(|(_, m)| **m);
+    let b_sequence = b_chars.iter().zip(&b_matched).filter(|(_, m)| **m);
+    let transpositions = a_sequence
+        .zip(b_sequence)
+        .filter(|((a_char, _), (b_char, _))| a_char != b_char)
+        .count()
+        / 2;
+
+    let matches = matches as f64;
+    (matches / a_chars.len() as f64
+        + matches / b_chars.len() as f64
+        + (matches - transpositions as f64) / matches)
+        / 3.0
+}
+
+///
+/// Split free text into word tokens for indexing. Whitespace and punctuation separate tokens and
+/// empty tokens are dropped. This is a shortcut for [tokenize_with] that keeps the original case
+/// and numeric tokens.
+///
+/// # Example
+/// ```
+///  use rumtk_core::strings::tokenize;
+///  let tokens = tokenize("Pt. reports chest-pain, 2 days.");
+///  assert_eq!(vec!["Pt", "reports", "chest", "pain", "2", "days"], tokens);
+///```
+///
+pub fn tokenize(s: &str) -> Vec<RUMString> {
+    tokenize_with(s, false, true)
+}
+
+///
+/// Split free text into word tokens for indexing.
+///
+/// * If `lowercase` is true, tokens are lowercased.
+/// * If `keep_numbers` is false, tokens made only of digits are discarded.
+///
+pub fn tokenize_with(s: &str, lowercase: bool, keep_numbers: bool) -> Vec<RUMString> {
+    s.split(|c: char| !c.is_alphanumeric())
+        .filter(|token| !token.is_empty())
+        .filter(|token| keep_numbers || !token.chars().all(char::is_numeric))
+        .map(|token| match lowercase {
+            true => RUMString::from(token.to_lowercase()),
+            false => RUMString::from(token),
+        })
+        .collect()
+}
+
+///
+/// Mask the letters and digits of a value so it can be logged without leaking PHI.
+///
+/// # Example
+/// ```
+///  use rumtk_core::strings::{redact, RedactionPolicy, RUMString};
+///  let name = RUMString::from("DOE^JOHN");
+///  assert_eq!("***^****", redact(&name, RedactionPolicy::Full));
+///  assert_eq!("D*E^J**N", redact(&name, RedactionPolicy::KeepFirstLast));
+///  assert_eq!("***^**HN", redact(&name, RedactionPolicy::KeepLast(2)));
+///```
+///
+pub fn redact(s: &str, policy: RedactionPolicy) -> RUMString {
+    let chars: Vec<char> = s.chars().collect();
+    let mut redacted = RUMString::with_capacity(s.len());
+    match policy {
+        RedactionPolicy::Full => {
+            for c in chars {
+                redacted.push(mask_alphanumeric(c));
+            }
+        }
+        RedactionPolicy::KeepFirstLast => {
+            let mut i = 0;
+            while i < chars.len() {
+                if !chars[i].is_alphanumeric() {
+                    redacted.push(chars[i]);
+                    i += 1;
+                    continue;
+                }
+                let start = i;
+                while i < chars.len() && chars[i].is_alphanumeric() {
+                    i += 1;
+                }
+                let word = &chars[start..i];
+                for (j, c) in word.iter().enumerate() {
+                    if word.len() > 2 && (j == 0 || j == word.len() - 1) {
+                        redacted.push(*c);
+                    } else {
+                        redacted.push(REDACTION_MASK_CHAR);
+                    }
+                }
+            }
+        }
+        RedactionPolicy::KeepLast(n) => {
+            let total = chars.iter().filter(|c| c.is_alphanumeric()).count();
+            let mut seen = 0;
+            for c in chars {
+                if c.is_alphanumeric() {
+                    seen += 1;
+                    if seen + n > total {
+                        redacted.push(c);
+                        continue;
+                    }
+                }
+                redacted.push(mask_alphanumeric(c));
+            }
+        }
+    }
+    redacted
+}
+
+///
+/// Same as [redact], using the policy set in
+/// [RumtkConfig::redaction_policy](crate::config::RumtkConfig::redaction_policy).
+///
+/// # Example
+/// ```
+///  use rumtk_core::config::update_config;
+///  use rumtk_core::strings::{redact_default, RedactionPolicy};
+///  update_config(|c| c.redaction_policy = RedactionPolicy::KeepLast(2));
+///  assert_eq!("***^**HN", redact_default("DOE^JOHN"));
+///```
+///
+pub fn redact_default(s: &str) -> RUMString {
+    redact(s, config().redaction_policy)
+}
+
+fn mask_alphanumeric(c: char) -> char {
+    if c.is_alphanumeric() {
+        REDACTION_MASK_CHAR
+    } else {
+        c
+    }
+}
+
+///
+/// Normalize a string to the requested Unicode normalization form so names coming from different
+/// systems compare equal regardless of whether accents were sent composed or decomposed.
+///
+/// Requires the `unicode-normalization` feature.
+///
+/// # Example
+/// ```
+///  use rumtk_core::strings::{normalize_unicode, NormalizationForm, RUMString};
+///  let decomposed = RUMString::from("Jose\u{301}");
+///  assert_eq!("José", normalize_unicode(&decomposed, NormalizationForm::NFC));
+///```
+///
+#[cfg(feature = "unicode-normalization")]
+pub fn normalize_unicode(s: &str, form: NormalizationForm) -> RUMString {
+    match form {
+        NormalizationForm::NFC => s.nfc().collect::<RUMString>(),
+        NormalizationForm::NFD => s.nfd().collect::<RUMString>(),
+    }
+}
+
+///
+/// Truncate a string to at most `max` grapheme clusters. Multibyte characters and clusters made of
+/// several codepoints (accents, emoji sequences) are never split.
+///
+/// # Example
+/// ```
+///  use rumtk_core::strings::{truncate_graphemes, RUMString};
+///  let name = RUMString::from("Jose\u{301} Nu\u{301}n\u{303}ez");
+///  assert_eq!("Jose\u{301}", truncate_graphemes(&name, 4));
+///```
+///
+pub fn truncate_graphemes(s: &str, max: usize) -> RUMString {
+    match s.grapheme_indices(true).nth(max) {
+        Some((end, _)) => RUMString::from(&s[..end]),
+        None => RUMString::from(s),
+    }
+}
+
+///
+/// Same as [truncate_graphemes], but if the string had to be truncated, its last visible grapheme
+/// is replaced with an ellipsis (`…`) so the output is still at most `max` grapheme clusters.
+///
+pub fn truncate_graphemes_ellipsis(s: &str, max: usize) -> RUMString {
+    if s.grapheme_indices(true).nth(max).is_none() {
+        return RUMString::from(s);
+    }
+    if max == 0 {
+        return RUMString::from(EMPTY_STRING);
+    }
+    let mut truncated = truncate_graphemes(s, max - 1);
+    truncated.push_str(ELLIPSIS_STR);
+    truncated
+}
+
+///
+/// Wrap text into lines of at most `width` grapheme clusters, breaking on whitespace.
+///
+/// Embedded newlines always start a new line (blank lines are preserved as empty strings). Words
+/// longer than `width` are hard split across lines. Consecutive whitespace between words collapses
+/// into a single space. A `width` of 0 disables wrapping and only splits on newlines.
+///
+/// # Example
+/// ```
+///  use rumtk_core::strings::{word_wrap, RUMString};
+///  let note = RUMString::from("Patient tolerated the procedure well");
+///  let lines = word_wrap(&note, 16);
+///  assert_eq!(vec!["Patient", "tolerated the", "procedure well"], lines);
+///```
+///
+pub fn word_wrap(s: &str, width: usize) -> Vec<RUMString> {
+    let mut lines: Vec<RUMString> = Vec::new();
+    for paragraph in s.split('\n') {
+        let paragraph = paragraph.strip_suffix('\r').unwrap_or(paragraph);
+        if width == 0 {
+            lines.push(RUMString::from(paragraph));
+            continue;
+        }
+
+        let mut line = RUMString::with_capacity(width);
+        let mut line_width: usize = 0;
+        for word in paragraph.split_whitespace() {
+            let word_width = word.count_graphemes();
+            if line_width > 0 && line_width + 1 + word_width <= width {
+                line.push(' ');
+                line.push_str(word);
+                line_width += 1 + word_width;
+                continue;
+            }
+            if line_width > 0 {
+                lines.push(line);
+                line = RUMString::with_capacity(width);
+            }
+            let graphemes = word.get_graphemes();
+            let mut chunks = graphemes.chunks(width).peekable();
+            while let Some(chunk) = chunks.next() {
+                let piece = chunk.join_compact("");
+                if chunks.peek().is_some() {
+                    lines.push(piece);
+                } else {
+                    line_width = chunk.len();
+                    line = piece;
+                }
+            }
+        }
+        lines.push(line);
+    }
+    lines
+}
+
+///
+/// Replace every ASCII case-insensitive occurrence of `from` with `to`.
+///
+/// Matches are found left to right and do not overlap, so once a match is replaced scanning
+/// resumes right after it (`"aaa"` with `from = "AA"` replaces only the first two characters).
+/// Text that does not match is preserved as is. An empty `from` leaves the input unchanged.
+///
+/// # Example
+/// ```
+///  use rumtk_core::strings::{replace_all_ignore_case, RUMString};
+///  let message = RUMString::from("Pid|1\rpId|2");
+///  assert_eq!("PID|1\rPID|2", replace_all_ignore_case(&message, "pid", "PID"));
+///```
+///
+pub fn replace_all_ignore_case(s: &str, from: &str, to: &str) -> RUMString {
+    if from.is_empty() {
+        return RUMString::from(s);
+    }
+    let haystack = s.as_bytes();
+    let needle = from.as_bytes();
+    let mut replaced = RUMString::with_capacity(s.len());
+    let mut copied_until = 0;
+    let mut i = 0;
+    while i + needle.len() <= haystack.len() {
+        if haystack[i..i + needle.len()].eq_ignore_ascii_case(needle) {
+            replaced.push_str(&s[copied_until..i]);
+            replaced.push_str(to);
+            i += needle.len();
+            copied_until = i;
+        } else {
+            i += 1;
+        }
+    }
+    replaced.push_str(&s[copied_until..]);
+    replaced
+}
+
+///
+/// Returns whether any of the `needles` appears in the string. Empty needles are ignored since
+/// they would trivially match anything.
+///
+/// # Example
+/// ```
+///  use rumtk_core::strings::{contains_any, RUMString};
+///  let field = RUMString::from("DOE^JOHN");
+///  assert!(contains_any(&field, &["~", "^", "&"]));
+///  assert!(!contains_any(&field, &["~", "&"]));
+///```
+///
+pub fn contains_any(s: &str, needles: &[&str]) -> bool {
+    needles
+        .iter()
+        .any(|needle| !needle.is_empty() && s.contains(needle))
+}
+
+///
+/// Find the earliest occurrence of any of the `needles` in the string.
+///
+/// Returns `(position, needle_index)` where `position` is the byte offset of the match and
+/// `needle_index` is the index into `needles` of the needle that matched. When several needles
+/// match at the same position, the first one in `needles` wins. Empty needles are ignored.
+///
+pub fn find_any(s: &str, needles: &[&str]) -> Option<(usize, usize)> {
+    let mut earliest: Option<(usize, usize)> = None;
+    for (needle_index, needle) in needles.iter().enumerate() {
+        if needle.is_empty() {
+            continue;
+        }
+        if let Some(position) = s.find(needle) {
+            match earliest {
+                Some((best, _)) if best <= position => (),
+                _ => earliest = Some((position, needle_index)),
+            }
+        }
+    }
+    earliest
+}
+
+///
+/// Split a string on `delim` while keeping the delimiter at the end of each piece. The last piece
+/// only lacks the delimiter if the input did not end with one. Concatenating the pieces yields the
+/// original string. An empty input produces no pieces.
+///
+/// # Example
+/// ```
+///  use rumtk_core::strings::{split_keep_delimiter, RUMString};
+///  let message = RUMString::from("MSH|1\rPID|2\rOBX");
+///  assert_eq!(vec!["MSH|1\r", "PID|2\r", "OBX"], split_keep_delimiter(&message, '\r'));
+///```
+///
+pub fn split_keep_delimiter(s: &str, delim: char) -> Vec<RUMString> {
+    s.split_inclusive(delim).map(RUMString::from).collect()
+}
+
+///
+/// Lazily read records out of `reader`, yielding one [RUMString] per record split on the
+/// `terminator` byte (see [DEFAULT_RECORD_TERMINATOR]). The terminator is not included in the
+/// yielded records and a trailing terminator does not produce an extra empty record.
+///
+/// The input is buffered internally, so records spanning several underlying reads are stitched
+/// together. IO failures and records that are not valid UTF-8 are yielded as errors.
+///
+/// # Example
+/// ```
+///  use rumtk_core::strings::{read_rumstrings, DEFAULT_RECORD_TERMINATOR};
+///  let batch = "MSH|1\rPID|2\r".as_bytes();
+///  let records: Vec<_> = read_rumstrings(batch, DEFAULT_RECORD_TERMINATOR)
+///     .map(|record| record.unwrap())
+///     .collect();
+///  assert_eq!(vec!["MSH|1", "PID|2"], records);
+///```
+///
+pub fn read_rumstrings<R: Read>(
+    reader: R,
+    terminator: u8,
+) -> impl Iterator<Item = RUMResult<RUMString>> {
+    let mut buffered = BufReader::new(reader);
+    let mut record: Vec<u8> = Vec::new();
+    let mut done = false;
+    std::iter::from_fn(move || {
+        if done {
+            return None;
+        }
+        record.clear();
+        match buffered.read_until(terminator, &mut record) {
+            Ok(0) => {
+                done = true;
+                None
+            }
+            Ok(_) => {
+                if record.last() == Some(&terminator) {
+                    record.pop();
+                }
+                match RUMString::from_utf8(&record) {
+                    Ok(s) => Some(Ok(s)),
+                    Err(e) => Some(Err(RUMError::parse(format_compact!(
+                        "Record is not valid UTF-8 because {}!",
+                        e
+                    )))),
+                }
+            }
+            Err(e) => {
+                done = true;
+                Some(Err(RUMError::io(
+                    e.kind(),
+                    format_compact!("Failed to read record from stream because {}!", e),
+                )))
+            }
+        }
+    })
+}
+
+///
+/// Split a string into chunks of at most `size` bytes without ever splitting a multibyte
+/// character. Chunks end early when the next character would not fit, so the final chunk (and
+/// occasionally others) may be shorter than `size`.
+///
+/// If a single character is wider than `size`, it gets a chunk of its own since it cannot be
+/// split. A `size` of 0 returns the whole input as one chunk. An empty input yields no chunks.
+///
+/// # Example
+/// ```
+///  use rumtk_core::strings::{chunk, RUMString};
+///  let message = RUMString::from("I ❤ HL7");
+///  assert_eq!(vec!["I ", "❤ ", "HL7"], chunk(&message, 4));
+///```
+///
+pub fn chunk(s: &str, size: usize) -> Vec<RUMString> {
+    if s.is_empty() {
+        return Vec::new();
+    }
+    if size == 0 {
+        return vec![RUMString::from(s)];
+    }
+    let mut chunks: Vec<RUMString> = Vec::with_capacity(s.len() / size + 1);
+    let mut start = 0;
+    while start < s.len() {
+        let mut end = (start + size).min(s.len());
+        while !s.is_char_boundary(end) {
+            end -= 1;
+        }
+        if end == start {
+            end = start + s[start..].chars().next().map_or(1, |c| c.len_utf8());
+        }
+        chunks.push(RUMString::from(&s[start..end]));
+        start = end;
+    }
+    chunks
+}
+
+///
+/// Pad the start of a string with `fill` until it is `width` characters long. Strings that are
+/// already `width` characters or longer are returned unchanged.
+///
+/// # Example
+/// ```
+///  use rumtk_core::strings::{pad_left, RUMString};
+///  assert_eq!("00042", pad_left(&RUMString::from("42"), 5, '0'));
+///```
+///
+pub fn pad_left(s: &str, width: usize, fill: char) -> RUMString {
+    let missing = width.saturating_sub(s.chars().count());
+    let mut padded = RUMString::with_capacity(s.len() + missing * fill.len_utf8());
+    for _ in 0..missing {
+        padded.push(fill);
+    }
+    padded.push_str(s);
+    padded
+}
+
+///
+/// Pad the end of a string with `fill` until it is `width` characters long. Strings that are
+/// already `width` characters or longer are returned unchanged.
+///
+/// # Example
+/// ```
+///  use rumtk_core::strings::{pad_right, RUMString};
+///  assert_eq!("DOE  ", pad_right(&RUMString::from("DOE"), 5, ' '));
+///```
+///
+pub fn pad_right(s: &str, width: usize, fill: char) -> RUMString {
+    let missing = width.saturating_sub(s.chars().count());
+    let mut padded = RUMString::with_capacity(s.len() + missing * fill.len_utf8());
+    padded.push_str(s);
+    for _ in 0..missing {
+        padded.push(fill);
+    }
+    padded
+}
+
+///
+/// Format an integer with `sep` between each group of thousands.
+///
+/// # Example
+/// ```
+///  use rumtk_core::strings::format_thousands;
+///  assert_eq!("-1,234,567", format_thousands(-1234567, ','));
+///```
+///
+pub fn format_thousands(n: i64, sep: char) -> RUMString {
+    let grouped = group_thousands(&n.unsigned_abs().to_compact_string(), sep);
+    match n < 0 {
+        true => format_compact!("-{}", grouped),
+        false => grouped,
+    }
+}
+
+///
+/// Format a float rounded to `decimals` decimal places with `sep` between each group of thousands
+/// in the integer part. Values that round to zero are never printed with a negative sign.
+/// `NaN` and infinities are returned as Rust would normally display them.
+///
+/// # Example
+/// ```
+///  use rumtk_core::strings::format_thousands_f64;
+///  assert_eq!("1,234,567.89", format_thousands_f64(1234567.891, ',', 2));
+///```
+///
+pub fn format_thousands_f64(n: f64, sep: char, decimals: usize) -> RUMString {
+    if !n.is_finite() {
+        return n.to_compact_string();
+    }
+    let formatted = format_compact!("{:.*}", decimals, n.abs());
+    let (integer, fraction) = match formatted.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (formatted.as_str(), None),
+    };
+    let is_zero = formatted.bytes().all(|b| b == b'0' || b == b'.');
+    let mut result = RUMString::with_capacity(formatted.len() + formatted.len() / 3 + 1);
+    if n < 0.0 && !is_zero {
+        result.push('-');
+    }
+    result.push_str(&group_thousands(integer, sep));
+    if let Some(fraction) = fraction {
+        result.push('.');
+        result.push_str(fraction);
+    }
+    result
+}
+
+///
+/// Insert `sep` between each group of 3 digits, counting from the right.
+///
+fn group_thousands(digits: &str, sep: char) -> RUMString {
+    let mut grouped = RUMString::with_capacity(digits.len() + digits.len() / 3 * sep.len_utf8());
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            grouped.push(sep);
+        }
+        grouped.push(c);
+    }
+    grouped
+}
+
+#[cfg(feature = "collation")]
+thread_local! {
+    static COLLATORS: std::cell::RefCell<crate::cache::RUMCache<Locale, CollatorBorrowed<'static>>> =
+        std::cell::RefCell::new(crate::cache::RUMCache::default());
+}
+
+///
+/// Produce a collation key for a string following the sorting rules of `locale`. Comparing keys
+/// byte wise gives the same ordering as comparing the strings with the locale's collation, so a
+/// `Vec<RUMString>` can be sorted with `sort_by_cached_key`.
+///
+/// Collators are built once per locale per thread and reused. If no collation data is available
+/// for `locale`, the root (language neutral) collation is used instead.
+///
+/// Requires the `collation` feature.
+///
+/// ⚠️ Keys should not be stored durably as they may change with updates to the Unicode or CLDR data.
+///
+/// # Example
+/// ```
+///  use rumtk_core::strings::{locale, sort_key, RUMString};
+///  let mut names = vec![RUMString::from("Zorn"), RUMString::from("Östberg")];
+///  names.sort_by_cached_key(|name| sort_key(name, &locale!("de")));
+///  assert_eq!(vec!["Östberg", "Zorn"], names);
+///```
+///
+#[cfg(feature = "collation")]
+pub fn sort_key(s: &str, locale: &Locale) -> Vec<u8> {
+    COLLATORS.with(|collators| {
+        let mut collators = collators.borrow_mut();
+        let collator = collators.entry(locale.clone()).or_insert_with(|| {
+            Collator::try_new(locale.into(), CollatorOptions::default())
+                .or_else(|_| Collator::try_new(Default::default(), CollatorOptions::default()))
+                .expect("Root collation data is compiled into the binary!")
+        });
+        let mut key: Vec<u8> = Vec::with_capacity(s.len() * 2);
+        let Ok(()) = collator.write_sort_key_to(s, &mut key);
+        key
+    })
+}
+
+///
+/// Build a [RUMString] from bytes that may not be valid UTF-8. Invalid sequences are replaced
+/// with the Unicode replacement character (`U+FFFD`).
+///
+/// # Example
+/// ```
+///  use rumtk_core::strings::rumstring_from_utf8_lossy;
+///  let decoded = rumstring_from_utf8_lossy(b"DOE\xffJOHN");
+///  assert_eq!("DOE\u{FFFD}JOHN", &decoded, "Did not get expected string! Got {}!", &decoded);
+///```
+///
+pub fn rumstring_from_utf8_lossy(bytes: &[u8]) -> RUMString {
+    RUMString::from_utf8_lossy(bytes)
+}
+
+///
+/// Build a [RUMString] from bytes, failing if the bytes are not valid UTF-8.
+///
+pub fn rumstring_from_utf8(bytes: &[u8]) -> RUMResult<RUMString> {
+    match RUMString::from_utf8(bytes) {
+        Ok(s) => Ok(s),
+        Err(e) => Err(RUMError::parse(format_compact!(
+            "Failed to decode bytes as UTF-8 because {}!",
+            e
+        ))),
+    }
+}
+
+///
+/// Compute a stable 64-bit hash (FNV-1a) of the contents of a string.
+///
+/// Unlike [std::hash::Hash] with a randomized hasher, the result is the same across runs and
+/// platforms, so it can be persisted for deduplication and change detection. It is not a
+/// cryptographic hash. Enable the `blake3` feature and use [content_digest] when collision
+/// resistance matters.
+///
+/// # Example
+/// ```
+///  use rumtk_core::strings::content_hash;
+///  assert_eq!(0xcbf29ce484222325, content_hash(""));
+///```
+///
+pub fn content_hash(s: &str) -> u64 {
+    s.as_bytes().iter().fold(FNV_OFFSET_BASIS, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(FNV_PRIME)
+    })
+}
+
+///
+/// Compute the 32-byte BLAKE3 digest of the contents of a string.
+///
+/// Requires the `blake3` feature.
+///
+#[cfg(feature = "blake3")]
+pub fn content_digest(s: &str) -> [u8; 32] {
+    *blake3::hash(s.as_bytes()).as_bytes()
+}
+
+///
+/// Compare two strings for equality in time that depends only on their lengths, not on where
+/// the first differing byte is. Use it when comparing secrets such as API tokens or HMACs.
+///
+/// The length check does return early, so the length of the secret is not hidden.
+///
+/// # Example
+/// ```
+///  use rumtk_core::strings::constant_time_eq;
+///  let token = "s3cr3t-t0k3n";
+///  assert!(constant_time_eq(token, "s3cr3t-t0k3n"));
+///  assert!(!constant_time_eq(token, "s3cr3t-t0k3N"));
+///```
+///
+pub fn constant_time_eq(a: &str, b: &str) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let difference = a
+        .as_bytes()
+        .iter()
+        .zip(b.as_bytes())
+        .fold(0u8, |difference, (a_byte, b_byte)| {
+            difference | (a_byte ^ b_byte)
+        });
+    std::hint::black_box(difference) == 0
+}
+
+///
+/// Copy a NUL-terminated C string received over FFI into a [RUMString].
+///
+/// A null `ptr` or bytes that are not valid UTF-8 produce an error instead of undefined behavior
+/// or a panic.
+///
+/// # Safety
+///
+/// If `ptr` is not null, it must point to a valid NUL-terminated string that stays alive and
+/// unmodified for the duration of this call. See [std::ffi::CStr::from_ptr] for the full contract.
+///
+/// # Example
+/// ```
+///  use std::ffi::CString;
+///  use rumtk_core::strings::rumstring_from_cstr_ptr;
+///  let c_string = CString::new("MSH|^~\\&|ADT").unwrap();
+///  let s = unsafe { rumstring_from_cstr_ptr(c_string.as_ptr()) }.unwrap();
+///  assert_eq!("MSH|^~\\&|ADT", &s, "Did not get expected string! Got {}!", &s);
+///```
+///
+pub unsafe fn rumstring_from_cstr_ptr(ptr: *const c_char) -> RUMResult<RUMString> {
+    if ptr.is_null() {
+        return Err(RUMError::other(format_compact!(
+            "Cannot build a string from a null C string pointer!"
+        )));
+    }
+    rumstring_from_utf8(CStr::from_ptr(ptr).to_bytes())
+}
+
+///
+/// Count the non-overlapping occurrences of `needle` in `s`, scanning left to right.
+///
+/// An empty `needle` is treated as never matching and returns `0`.
+///
+/// # Example
+/// ```
+///  use rumtk_core::strings::count_occurrences;
+///  let segment = "PID|1||12345^^^MRN||DOE^JOHN";
+///  assert_eq!(5, count_occurrences(segment, "|"));
+///```
+///
+pub fn count_occurrences(s: &str, needle: &str) -> usize {
+    if needle.is_empty() {
+        return 0;
+    }
+    s.matches(needle).count()
+}
+
+///
+/// Convert a person name to display casing. HL7 names frequently arrive all caps.
+///
+/// * Each word is capitalized, including the parts of hyphenated names and the part after an
+///   apostrophe (`O'Brien`).
+/// * `Mc` names get their third letter capitalized (`McDonald`).
+/// * Particles such as `van`, `de` or `la` are lowercased unless they start the name or component.
+/// * Generational suffixes (`II`, `III`, `IV`) stay uppercase.
+///
+/// HL7 component separators (`^`) are kept, and each component is cased as its own name.
+///
+/// # Example
+/// ```
+///  use rumtk_core::strings::to_name_case;
+///  let name = to_name_case("MCDONALD-O'BRIEN^LUDWIG VAN^III");
+///  assert_eq!("McDonald-O'Brien^Ludwig van^III", &name, "Did not get expected name! Got {}!", &name);
+///```
+///
+pub fn to_name_case(s: &str) -> RUMString {
+    let mut cased = RUMString::with_capacity(s.len());
+    let mut word = RUMString::default();
+    let mut first_word = true;
+    let mut after_space = false;
+    for c in s.chars() {
+        if c.is_alphanumeric() {
+            word.push(c);
+            continue;
+        }
+        if !word.is_empty() {
+            cased.push_str(&name_case_word(&word, after_space && !first_word));
+            word.clear();
+            first_word = false;
+        }
+        match c {
+            '^' => first_word = true,
+            _ => after_space = c.is_whitespace(),
+        }
+        cased.push(c);
+    }
+    if !word.is_empty() {
+        cased.push_str(&name_case_word(&word, after_space && !first_word));
+    }
+    cased
+}
+
+fn name_case_word(word: &str, allow_particle: bool) -> RUMString {
+    let lowercase = word.to_lowercase();
+    if allow_particle && NAME_PARTICLES.contains(&lowercase.as_str()) {
+        return RUMString::from(lowercase);
+    }
+    let uppercase = word.to_uppercase();
+    if NAME_SUFFIXES.contains(&uppercase.as_str()) {
+        return RUMString::from(uppercase);
+    }
+    match lowercase.strip_prefix(NAME_MC_PREFIX) {
+        Some(rest) if !rest.is_empty() => format_compact!("Mc{}", capitalize_word(rest)),
+        _ => capitalize_word(&lowercase),
+    }
+}
+
+fn capitalize_word(word: &str) -> RUMString {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => RUMString::default(),
+    }
+}
@@ -31,7 +31,7 @@ pub mod thread_primitives {
     use tokio::task::JoinHandle;
 
     /**************************** Globals **************************************/
-    pub static mut rt_cache: TokioRtCache = new_cache();
+    pub static rt_cache: TokioRtCache = new_cache();
     /**************************** Helpers ***************************************/
     pub fn init_cache(threads: &usize) -> SafeTokioRuntime {
         let mut builder = tokio::runtime::Builder::new_multi_thread();
@@ -107,6 +107,91 @@ pub mod threading_functions {
     }
 }
 
+///
+/// Shared worker pool for the toolkit's internal parallelism (e.g. sharded cache maintenance or
+/// mapping work over Python). Using one bounded pool keeps the crate from oversubscribing cores
+/// with ad hoc threads.
+///
+pub mod thread_pool {
+    use crate::config::config;
+    use crate::core::{RUMError, RUMResult};
+    use crate::threading::thread_primitives::{init_cache, SafeTokioRuntime};
+    use compact_str::format_compact;
+    use once_cell::sync::Lazy;
+    use tokio::runtime::Handle;
+    use tokio::task::JoinHandle;
+
+    /**************************** Types ***************************************/
+    pub type PoolTaskHandle<R> = JoinHandle<R>;
+
+    struct GlobalPool {
+        runtime: SafeTokioRuntime,
+        size: usize,
+    }
+
+    /**************************** Globals **************************************/
+    static GLOBAL_POOL: Lazy<GlobalPool> = Lazy::new(|| {
+        let size = config().thread_pool_size.max(1);
+        GlobalPool {
+            runtime: init_cache(&size),
+            size,
+        }
+    });
+
+    /**************************** Helpers ***************************************/
+
+    ///
+    /// Number of worker threads in the global pool. The pool is created on first use with
+    /// [RumtkConfig::thread_pool_size](crate::config::RumtkConfig::thread_pool_size) workers and
+    /// keeps that size for the life of the process.
+    ///
+    pub fn pool_size() -> usize {
+        GLOBAL_POOL.size
+    }
+
+    ///
+    /// Run `task` on the global pool. At most [pool_size] tasks run at the same time; the rest
+    /// wait in the queue.
+    ///
+    /// # Example
+    /// ```
+    ///  use rumtk_core::threading::thread_pool::{spawn, wait};
+    ///  let handle = spawn(|| (1..=10).sum::<u32>());
+    ///  assert_eq!(Ok(55), wait(handle));
+    ///```
+    ///
+    pub fn spawn<F, R>(task: F) -> PoolTaskHandle<R>
+    where
+        F: FnOnce() -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        GLOBAL_POOL.runtime.spawn(async move { task() })
+    }
+
+    ///
+    /// Block the current thread until the task finishes and return its result. A panic inside the
+    /// task is returned as an error.
+    ///
+    /// Calling this from async code, including a pool task, returns an error instead of blocking,
+    /// since a runtime cannot be blocked on from inside another runtime. The task keeps running
+    /// in that case; only its result is lost.
+    ///
+    pub fn wait<R>(handle: PoolTaskHandle<R>) -> RUMResult<R> {
+        if Handle::try_current().is_ok() {
+            return Err(RUMError::other(format_compact!(
+                "Cannot wait on a pool task from inside an async runtime!"
+            )));
+        }
+        match GLOBAL_POOL.runtime.block_on(handle) {
+            Ok(result) => Ok(result),
+            Err(e) => Err(RUMError::other(format_compact!(
+                "Pool task did not complete because {}!",
+                e
+            ))),
+        }
+    }
+}
+
 ///
 /// Main API for interacting with the threading back end. Remember, we use tokio as our executor.
 /// This means that by default, all jobs sent to the thread pool have to be async in nature.
@@ -119,7 +204,11 @@ pub mod threading_macros {
     ///
     /// First, let's make sure we have *tokio* initialized at least once. The runtime created here
     /// will be saved to the global context so the next call to this macro will simply grab a
-    /// reference to the previously initialized runtime.
+    /// handle to the previously initialized runtime.
+    ///
+    /// **Breaking change:** the macro used to evaluate to `&SafeTokioRuntime`. It now evaluates to
+    /// an owned [SafeTokioRuntime](thread_primitives::SafeTokioRuntime), which is an [Arc](std::sync::Arc)
+    /// clone of the cached runtime. Callers that passed the result on as `rt` should pass `&rt`.
     ///
     /// Passing nothing will default to initializing a runtime using the default number of threads
     /// for this system. This is typically equivalent to number of cores/threads for your CPU.
@@ -173,17 +262,13 @@ pub mod threading_macros {
             use $crate::rumtk_cache_fetch;
             use $crate::threading::thread_primitives::{init_cache, rt_cache};
             use $crate::threading::threading_functions::get_default_system_thread_count;
-            let rt = rumtk_cache_fetch!(
-                &mut rt_cache,
-                &get_default_system_thread_count(),
-                init_cache
-            );
+            let rt = rumtk_cache_fetch!(&rt_cache, &get_default_system_thread_count(), init_cache);
             rt
         }};
         ( $threads:expr ) => {{
             use $crate::rumtk_cache_fetch;
             use $crate::threading::thread_primitives::{init_cache, rt_cache};
-            let rt = rumtk_cache_fetch!(&mut rt_cache, $threads, init_cache);
+            let rt = rumtk_cache_fetch!(&rt_cache, $threads, init_cache);
             rt
         }};
     }
@@ -261,11 +346,13 @@ pub mod threading_macros {
     #[macro_export]
     macro_rules! rumtk_resolve_task {
         ( $rt:expr, $future:expr ) => {{
+            use $crate::core::RUMError;
             use $crate::strings::format_compact;
             //$rt.block_on(async move { $future.await }).unwrap()
-            match $rt.block_on(async move { $future.await }) {
+            let future = $future;
+            match $rt.block_on(future) {
                 Ok(r) => Ok(r),
-                Err(e) => Err(format_compact!("Task failed with {}", e)),
+                Err(e) => Err(RUMError::other(format_compact!("Task failed with {}", e))),
             }
         }};
     }